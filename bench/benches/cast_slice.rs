@@ -0,0 +1,37 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const BYTES: usize = 1 << 20;
+
+fn bench_cast_slice(c: &mut Criterion) {
+  // u64 storage so that the bytes are aligned for every target type
+  let words = vec![0x0123_4567_89AB_CDEF_u64; BYTES / 8];
+  let bytes: &[u8] = bytemuck::cast_slice(&words);
+
+  c.bench_function("cast_slice u8 -> u32 (1MB)", |b| {
+    b.iter(|| bytemuck::cast_slice::<u8, u32>(black_box(bytes)).len())
+  });
+  c.bench_function("cast_slice u8 -> u64 (1MB)", |b| {
+    b.iter(|| bytemuck::cast_slice::<u8, u64>(black_box(bytes)).len())
+  });
+
+  let wide: &[u32] = bytemuck::cast_slice(bytes);
+  c.bench_function("cast_slice u32 -> u8 (1MB)", |b| {
+    b.iter(|| bytemuck::cast_slice::<u32, u8>(black_box(wide)).len())
+  });
+  c.bench_function("cast_slice u64 -> u8 (1MB)", |b| {
+    b.iter(|| bytemuck::cast_slice::<u64, u8>(black_box(&words)).len())
+  });
+
+  // the bytes are only touched here, for comparison with a plain pass over
+  // the buffer
+  c.bench_function("sum u8 -> u32 (1MB)", |b| {
+    b.iter(|| {
+      bytemuck::cast_slice::<u8, u32>(black_box(bytes))
+        .iter()
+        .fold(0_u32, |acc, &x| acc.wrapping_add(x))
+    })
+  });
+}
+
+criterion_group!(benches, bench_cast_slice);
+criterion_main!(benches);
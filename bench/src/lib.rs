@@ -0,0 +1 @@
+//! This crate only holds the `bytemuck` benchmarks, see `benches/`.
@@ -9,7 +9,7 @@ use quote::quote;
 use syn::{parse_macro_input, DeriveInput, Result};
 
 use crate::traits::{
-  AnyBitPattern, Contiguous, Derivable, CheckedBitPattern, NoUninit, Pod, TransparentWrapper, Zeroable,
+  get_custom_bounds, AnyBitPattern, Contiguous, Derivable, CheckedBitPattern, NoUninit, Pod, TransparentWrapper, Zeroable,
 };
 
 /// Derive the `Pod` trait for a struct
@@ -24,6 +24,9 @@ use crate::traits::{
 /// - The struct must not contain any padding bytes
 /// - The struct contains no generic parameters
 ///
+/// For an array field like `[Cell; 100]` it's the element type `Cell` that
+/// gets checked, so any array length works.
+///
 /// ## Example
 ///
 /// ```rust
@@ -52,6 +55,9 @@ pub fn derive_pod(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// The following constraints need to be satisfied for the macro to succeed
 ///
 /// - All fields in the struct must to implement `AnyBitPattern`
+/// - The type must be a struct or union. Enums can't be `AnyBitPattern`, since
+///   only some bit patterns are valid discriminants. Use `CheckedBitPattern`
+///   for those instead.
 #[proc_macro_derive(AnyBitPattern)]
 pub fn derive_anybitpattern(
   input: proc_macro::TokenStream,
@@ -83,7 +89,25 @@ pub fn derive_anybitpattern(
 ///   b: u16,
 /// }
 /// ```
-#[proc_macro_derive(Zeroable)]
+///
+/// ## Custom bounds
+///
+/// The derive doesn't add any bounds to the impl beyond the type's own where
+/// clause. A generic type can give the bounds that the impl (and the check
+/// that all fields are `Zeroable`) should use with
+/// `#[zeroable(bound = "...")]`, which takes where-clause predicates.
+///
+/// ```rust
+/// # use bytemuck::Zeroable;
+///
+/// #[derive(Zeroable)]
+/// #[zeroable(bound = "T: Zeroable")]
+/// struct Pair<T> {
+///   a: T,
+///   b: u32,
+/// }
+/// ```
+#[proc_macro_derive(Zeroable, attributes(zeroable))]
 pub fn derive_zeroable(
   input: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
@@ -106,7 +130,12 @@ pub fn derive_zeroable(
 /// - All fields in the struct must implement `NoUninit`
 /// - The struct must be `#[repr(C)]` or `#[repr(transparent)]`
 /// - The struct must not contain any padding bytes
-/// - The struct must contain no generic parameters
+/// - The struct must contain no generic parameters, unless it is
+///   `#[repr(transparent)]`
+///
+/// A `#[repr(transparent)]` struct can't have padding of its own, so for those
+/// the derive only checks that the fields are `NoUninit`. The derive doesn't add
+/// any `Copy` requirement beyond the one on the `NoUninit` trait itself.
 ///
 /// If applied to an enum:
 /// - The enum must be explicit `#[repr(Int)]`
@@ -138,7 +167,27 @@ pub fn derive_no_uninit(
 ///
 /// If applied to an enum:
 /// - All requirements already checked by `NoUninit`, just impls the trait
-#[proc_macro_derive(CheckedBitPattern)]
+///
+/// ## Naming the invalid field
+///
+/// A struct can opt in to also deriving `validate_verbose` with
+/// `#[checked_bit_pattern(verbose)]`. Then
+/// `bytemuck::checked::try_from_bytes_verbose` names the first invalid field
+/// and gives its byte offset. The generated method uses
+/// `bytemuck::checked::FieldError`, so this needs a `bytemuck` that has it.
+///
+/// ```rust
+/// # use bytemuck::{CheckedBitPattern, NoUninit};
+///
+/// #[derive(Copy, Clone, NoUninit, CheckedBitPattern)]
+/// #[repr(C)]
+/// #[checked_bit_pattern(verbose)]
+/// struct Record {
+///   id: u32,
+///   letter: char,
+/// }
+/// ```
+#[proc_macro_derive(CheckedBitPattern, attributes(checked_bit_pattern))]
 pub fn derive_maybe_pod(
   input: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
@@ -158,9 +207,11 @@ pub fn derive_maybe_pod(
 /// - The struct must be `#[repr(transparent)]`
 /// - The struct must contain the `Wrapped` type
 ///
-/// If the struct only contains a single field, the `Wrapped` type will
-/// automatically be determined if there is more then one field in the struct,
-/// you need to specify the `Wrapped` type using `#[transparent(T)]`
+/// If the struct only contains a single field, or only a single field that
+/// isn't a `PhantomData`, `PhantomPinned`, `()`, or `[T; 0]`, the `Wrapped`
+/// type will automatically be determined. This also works when that field is a
+/// generic parameter. Otherwise you need to specify the `Wrapped` type using
+/// `#[transparent(T)]`.
 ///
 /// ## Example
 ///
@@ -175,6 +226,10 @@ pub fn derive_maybe_pod(
 ///   inner: u16,
 ///   extra: PhantomData<T>,
 /// }
+///
+/// #[derive(Copy, Clone, TransparentWrapper)]
+/// #[repr(transparent)]
+/// struct Tagged<T>(T, PhantomData<u8>);
 /// ```
 #[proc_macro_derive(TransparentWrapper, attributes(transparent))]
 pub fn derive_transparent(
@@ -221,6 +276,98 @@ pub fn derive_contiguous(
   proc_macro::TokenStream::from(expanded)
 }
 
+/// Derive `TryFrom<Int>` for a type that implements `Contiguous`
+///
+/// The conversion is done with `Contiguous::from_integer`, and an out of range
+/// value gives a `ContiguousOutOfRange` error holding the value and the valid
+/// range. This derive doesn't implement `Contiguous` itself, so you'll usually
+/// derive both.
+///
+/// ## Example
+///
+/// ```rust
+/// # use bytemuck_derive::{Contiguous, ContiguousTryFrom};
+/// # use std::convert::TryFrom;
+///
+/// #[derive(Debug, Copy, Clone, PartialEq, Contiguous, ContiguousTryFrom)]
+/// #[repr(u8)]
+/// enum Test {
+///   A = 0,
+///   B = 1,
+/// }
+///
+/// assert_eq!(Test::try_from(1), Ok(Test::B));
+/// assert!(Test::try_from(2).is_err());
+/// ```
+#[proc_macro_derive(ContiguousTryFrom)]
+pub fn derive_contiguous_try_from(
+  input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+  let name = &input.ident;
+  let (impl_generics, ty_generics, where_clause) =
+    input.generics.split_for_impl();
+
+  proc_macro::TokenStream::from(quote! {
+    impl #impl_generics ::core::convert::TryFrom<<#name #ty_generics as ::bytemuck::Contiguous>::Int> for #name #ty_generics #where_clause {
+      type Error = ::bytemuck::ContiguousOutOfRange<<#name #ty_generics as ::bytemuck::Contiguous>::Int>;
+
+      #[inline]
+      fn try_from(value: <#name #ty_generics as ::bytemuck::Contiguous>::Int) -> ::core::result::Result<Self, Self::Error> {
+        <Self as ::bytemuck::Contiguous>::from_integer(value)
+          .ok_or_else(|| ::bytemuck::ContiguousOutOfRange::new::<Self>(value))
+      }
+    }
+  })
+}
+
+/// Derive `PartialEq` and `Eq` for a type by comparing its bytes
+///
+/// This is just `bytes_of(self) == bytes_of(other)`, which for a large struct
+/// can be quicker than comparing it field by field.
+///
+/// The type must also be `NoUninit`, since that means it has no padding bytes
+/// and so two values are equal exactly when their bytes are. A type that isn't
+/// `NoUninit` fails to compile with this derive.
+///
+/// Note that for a type with float fields this is *not* the same as a derived
+/// `PartialEq`: `0.0` and `-0.0` compare unequal, and a `NaN` compares equal to
+/// a `NaN` with the same bits.
+///
+/// ## Example
+///
+/// ```rust
+/// # use bytemuck_derive::{ByteEq, NoUninit};
+///
+/// #[derive(Copy, Clone, NoUninit, ByteEq)]
+/// #[repr(C)]
+/// struct Test {
+///   a: u32,
+///   b: [u8; 4],
+/// }
+/// ```
+#[proc_macro_derive(ByteEq)]
+pub fn derive_byte_eq(
+  input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+  let input = parse_macro_input!(input as DeriveInput);
+  let name = &input.ident;
+  let (impl_generics, ty_generics, where_clause) =
+    input.generics.split_for_impl();
+
+  proc_macro::TokenStream::from(quote! {
+    impl #impl_generics ::core::cmp::PartialEq for #name #ty_generics #where_clause {
+      #[inline]
+      fn eq(&self, other: &Self) -> bool {
+        fn assert_no_uninit<T: ::bytemuck::NoUninit>() {}
+        assert_no_uninit::<Self>();
+        ::bytemuck::bytes_of(self) == ::bytemuck::bytes_of(other)
+      }
+    }
+    impl #impl_generics ::core::cmp::Eq for #name #ty_generics #where_clause {}
+  })
+}
+
 /// Basic wrapper for error handling
 fn derive_marker_trait<Trait: Derivable>(input: DeriveInput) -> TokenStream {
   derive_marker_trait_inner::<Trait>(input)
@@ -228,8 +375,16 @@ fn derive_marker_trait<Trait: Derivable>(input: DeriveInput) -> TokenStream {
 }
 
 fn derive_marker_trait_inner<Trait: Derivable>(
-  input: DeriveInput,
+  mut input: DeriveInput,
 ) -> Result<TokenStream> {
+  // Custom bounds go on the generics, so that both the impl and the field
+  // checks in `asserts` get to use them.
+  if let Some(attr_name) = Trait::bound_attribute() {
+    if let Some(bounds) = get_custom_bounds(&input.attrs, attr_name)? {
+      input.generics.make_where_clause().predicates.extend(bounds);
+    }
+  }
+
   let name = &input.ident;
 
   let (impl_generics, ty_generics, where_clause) =
@@ -39,6 +39,10 @@ pub trait Derivable {
   ) -> Result<(TokenStream, TokenStream)> {
     Ok((quote!(), quote!()))
   }
+  /// The attribute that can give custom bounds with `#[attr(bound = "...")]`
+  fn bound_attribute() -> Option<&'static str> {
+    None
+  }
 }
 
 pub struct Pod;
@@ -110,7 +114,12 @@ impl Derivable for AnyBitPattern {
     match &input.data {
       Data::Union(_) => Ok(quote!()), // unions are always `AnyBitPattern`
       Data::Struct(_) => generate_fields_are_trait(input, Self::ident()),
-      Data::Enum(_) => bail!("Deriving AnyBitPattern is not supported for enums"),
+      Data::Enum(DataEnum { enum_token, .. }) => bail!("\
+        Deriving AnyBitPattern is not supported for enums, because an enum's \
+        discriminant only has a limited set of valid values, so not every bit \
+        pattern is a valid instance of the enum. Consider deriving \
+        CheckedBitPattern instead, which checks the discriminant at runtime\
+      " => enum_token),
     }
   }
 }
@@ -122,11 +131,20 @@ impl Derivable for Zeroable {
     quote!(::bytemuck::Zeroable)
   }
 
+  fn bound_attribute() -> Option<&'static str> {
+    Some("zeroable")
+  }
+
   fn asserts(input: &DeriveInput) -> Result<TokenStream> {
     match &input.data {
       Data::Union(_) => Ok(quote!()), // unions are always `Zeroable`
       Data::Struct(_) => generate_fields_are_trait(input, Self::ident()),
-      Data::Enum(_) => bail!("Deriving Zeroable is not supported for enums"),
+      Data::Enum(DataEnum { enum_token, .. }) => bail!("\
+        Deriving Zeroable is not supported for enums, because the all-zero bit \
+        pattern is only a valid instance of the enum if one of the variants \
+        has a discriminant of zero. If it does, implement Zeroable for the \
+        enum manually\
+      " => enum_token),
     }
   }
 }
@@ -157,13 +175,22 @@ impl Derivable for NoUninit {
   }
 
   fn asserts(input: &DeriveInput) -> Result<TokenStream> {
-    if !input.generics.params.is_empty() {
+    // A `repr(transparent)` struct has exactly the layout of its one non-ZST
+    // field, so if every field is `NoUninit` there's no padding to look for.
+    // That's also what lets us accept generic transparent wrappers.
+    let is_transparent = get_repr(&input.attrs)?.repr == Repr::Transparent;
+
+    if !is_transparent && !input.generics.params.is_empty() {
       bail!("NoUninit cannot be derived for structs containing generic parameters because the padding requirements can't be verified for generic structs");
     }
 
     match &input.data {
       Data::Struct(DataStruct { .. }) => {
-        let assert_no_padding = generate_assert_no_padding(&input)?;
+        let assert_no_padding = if !is_transparent {
+          Some(generate_assert_no_padding(&input)?)
+        } else {
+          None
+        };
         let assert_fields_are_no_padding =
           generate_fields_are_trait(&input, Self::ident())?;
 
@@ -203,10 +230,12 @@ impl Derivable for CheckedBitPattern {
     let repr = get_repr(attributes)?;
     match ty {
       Data::Struct(_) => match repr.repr {
-        Repr::C | Repr::Transparent => Ok(()),
+        Repr::C | Repr::Transparent => get_checked_bit_pattern_verbose(attributes).map(|_| ()),
         _ => bail!("CheckedBitPattern derive requires the struct to be #[repr(C)] or #[repr(transparent)]"),
       },
-      Data::Enum(_) => if repr.repr.is_integer() {
+      Data::Enum(_) => if get_checked_bit_pattern_verbose(attributes)? {
+        bail!("#[checked_bit_pattern(verbose)] is only supported for structs")
+      } else if repr.repr.is_integer() {
         Ok(())
       } else {
         bail!("CheckedBitPattern requires the enum to be an explicit #[repr(Int)]")
@@ -253,16 +282,32 @@ impl TransparentWrapper {
   ) -> Option<TokenStream> {
     let transparent_param = get_simple_attr(attributes, "transparent");
     transparent_param.map(|ident| ident.to_token_stream()).or_else(|| {
-      let mut types = get_field_types(&fields);
+      // Fields that are obviously zero-sized (`PhantomData` and friends) can
+      // never be the wrapped field, so we only guess among the rest.
+      let mut types =
+        get_field_types(&fields).filter(|ty| !is_known_zero_sized(ty));
       let first_type = types.next();
       if let Some(_) = types.next() {
-        // can't guess param type if there is more than one field
+        // can't guess param type if there is more than one non-ZST field
         return None;
       } else {
         first_type.map(|ty| ty.to_token_stream())
       }
     })
   }
+
+  fn get_required_wrapper_type(input: &DeriveInput) -> Result<TokenStream> {
+    let fields = get_struct_fields(input)?;
+
+    match Self::get_wrapper_type(&input.attrs, &fields) {
+      | Some(ty) => Ok(ty),
+      | None => bail!("\
+        when deriving TransparentWrapper for a struct with more than one \
+        field that isn't known to be zero-sized you need to specify the \
+        transparent field using #[transparent(T)]\
+      "),
+    }
+  }
 }
 
 impl Derivable for TransparentWrapper {
@@ -271,23 +316,13 @@ impl Derivable for TransparentWrapper {
   }
 
   fn generic_params(input: &DeriveInput) -> Result<TokenStream> {
-    let fields = get_struct_fields(input)?;
-
-    match Self::get_wrapper_type(&input.attrs, &fields) {
-      | Some(ty) => Ok(quote!(<#ty>)),
-      | None => bail!("\
-        when deriving TransparentWrapper for a struct with more than one field \
-        you need to specify the transparent field using #[transparent(T)]\
-      "),
-    }
+    let ty = Self::get_required_wrapper_type(input)?;
+    Ok(quote!(<#ty>))
   }
 
   fn asserts(input: &DeriveInput) -> Result<TokenStream> {
     let fields = get_struct_fields(input)?;
-    let wrapped_type = match Self::get_wrapper_type(&input.attrs, &fields) {
-      Some(wrapped_type) => wrapped_type.to_string(),
-      None => unreachable!(), /* other code will already reject this derive */
-    };
+    let wrapped_type = Self::get_required_wrapper_type(input)?.to_string();
     let mut wrapped_fields = fields
       .iter()
       .filter(|field| field.ty.to_token_stream().to_string() == wrapped_type);
@@ -401,6 +436,45 @@ fn get_field_types<'a>(
   fields.iter().map(|field| &field.ty)
 }
 
+/// Check if a field type is one that's always zero-sized, no matter what
+/// generic parameters it's given.
+///
+/// This is purely syntactic, so it only catches the usual marker types, either
+/// by their bare name or through `core::marker` / `std::marker`. Anything else
+/// (including some other type that happens to be named `PhantomData`) is
+/// assumed to possibly be non-zero-sized.
+fn is_known_zero_sized(ty: &Type) -> bool {
+  match ty {
+    Type::Tuple(TypeTuple { elems, .. }) => elems.is_empty(),
+    Type::Array(TypeArray {
+      len: Expr::Lit(ExprLit { lit: Lit::Int(int), .. }),
+      ..
+    }) => int.base10_parse::<usize>().map(|n| n == 0).unwrap_or(false),
+    Type::Path(TypePath { qself: None, path }) => {
+      let segments: Vec<_> = path.segments.iter().collect();
+      let (module, last) = match &segments[..] {
+        [last] if path.leading_colon.is_none() => (None, last),
+        [krate, marker, last]
+          if marker.ident == "marker" && marker.arguments.is_empty() =>
+        {
+          (Some(krate), last)
+        }
+        _ => return false,
+      };
+      let module_is_marker = module.map_or(true, |krate| {
+        (krate.ident == "core" || krate.ident == "std")
+          && krate.arguments.is_empty()
+      });
+      module_is_marker
+        && (last.ident == "PhantomData" || last.ident == "PhantomPinned")
+    }
+    Type::Paren(TypeParen { elem, .. }) | Type::Group(TypeGroup { elem, .. }) => {
+      is_known_zero_sized(elem)
+    }
+    _ => false,
+  }
+}
+
 fn generate_checked_bit_pattern_struct(
   input_ident: &Ident, fields: &Fields, attrs: &[Attribute],
 ) -> Result<(TokenStream, TokenStream)> {
@@ -419,11 +493,41 @@ fn generate_checked_bit_pattern_struct(
     .collect::<Vec<_>>();
   let field_tys = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
 
+  let field_labels = fields
+    .iter()
+    .enumerate()
+    .map(|(i, field)| match &field.ident {
+      Some(ident) => ident.to_string(),
+      None => i.to_string(),
+    })
+    .collect::<Vec<_>>();
+
   let field_name = &field_names[..];
   let field_ty = &field_tys[..];
+  let field_label = &field_labels[..];
 
   let derive_dbg = quote!(#[cfg_attr(not(target_arch = "spirv"), derive(Debug))]);
 
+  let validate_verbose = if get_checked_bit_pattern_verbose(attrs)? {
+    quote! {
+        #[inline]
+        fn validate_verbose(bits: &#bits_ty) -> ::core::result::Result<(), ::bytemuck::checked::FieldError> {
+            let base = bits as *const #bits_ty as usize;
+            #(
+              if !<#field_ty as ::bytemuck::CheckedBitPattern>::is_valid_bit_pattern(&bits.#field_name) {
+                return ::core::result::Result::Err(::bytemuck::checked::FieldError {
+                  field: ::core::option::Option::Some(#field_label),
+                  offset: &bits.#field_name as *const _ as usize - base,
+                });
+              }
+            )*
+            ::core::result::Result::Ok(())
+        }
+    }
+  } else {
+    quote!()
+  };
+
   Ok((
     quote! {
         #repr
@@ -441,6 +545,8 @@ fn generate_checked_bit_pattern_struct(
         fn is_valid_bit_pattern(bits: &#bits_ty) -> bool {
             #(<#field_ty as ::bytemuck::CheckedBitPattern>::is_valid_bit_pattern(&bits.#field_name) && )* true
         }
+
+        #validate_verbose
     },
   ))
 }
@@ -527,6 +633,17 @@ fn generate_assert_no_padding(
   };})
 }
 
+/// Gives the innermost element type of a (possibly nested) array type, or the
+/// type itself if it's not an array.
+fn array_element_type(ty: &Type) -> &Type {
+  match ty {
+    Type::Array(TypeArray { elem, .. }) => array_element_type(elem),
+    Type::Paren(TypeParen { elem, .. }) => array_element_type(elem),
+    Type::Group(TypeGroup { elem, .. }) => array_element_type(elem),
+    _ => ty,
+  }
+}
+
 /// Check that all fields implement a given trait
 fn generate_fields_are_trait(
   input: &DeriveInput, trait_: TokenStream,
@@ -535,7 +652,10 @@ fn generate_fields_are_trait(
     input.generics.split_for_impl();
   let fields = get_fields(input)?;
   let span = input.span();
-  let field_types = get_field_types(&fields);
+  // An array is the trait whenever its element type is, but without the
+  // `min_const_generics` feature bytemuck only has array impls for some
+  // lengths, so we check the element type to not reject valid structs.
+  let field_types = get_field_types(&fields).map(array_element_type);
   Ok(quote_spanned! {span => #(const _: fn() = || {
       #[allow(clippy::missing_const_for_fn)]
       fn check #impl_generics () #where_clause {
@@ -571,6 +691,45 @@ fn get_simple_attr(attributes: &[Attribute], attr_name: &str) -> Option<Ident> {
   None
 }
 
+/// check for a #[checked_bit_pattern(verbose)] attribute
+fn get_checked_bit_pattern_verbose(attributes: &[Attribute]) -> Result<bool> {
+  match get_simple_attr(attributes, "checked_bit_pattern") {
+    None => Ok(false),
+    Some(ident) if ident == "verbose" => Ok(true),
+    Some(ident) => bail!("expected `#[checked_bit_pattern(verbose)]`" => ident),
+  }
+}
+
+/// get the where predicates of a #[foo(bound = "T: Bar")] attribute
+pub fn get_custom_bounds(
+  attributes: &[Attribute], attr_name: &str,
+) -> Result<Option<Punctuated<WherePredicate, Token![,]>>> {
+  let mut bounds = None;
+  for attr in attributes.iter().filter(|attr| attr.path.is_ident(attr_name)) {
+    let list = match attr.parse_meta()? {
+      Meta::List(list) => list,
+      meta => bail!("expected `bound = \"...\"`" => meta),
+    };
+    for nested in list.nested {
+      match nested {
+        NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+          path,
+          lit: Lit::Str(lit),
+          ..
+        })) if path.is_ident("bound") => {
+          if bounds.is_some() {
+            bail!("duplicate `bound` attribute" => path);
+          }
+          let parser = Punctuated::<WherePredicate, Token![,]>::parse_terminated;
+          bounds = Some(lit.parse_with(parser)?);
+        }
+        other => bail!("expected `bound = \"...\"`" => other),
+      }
+    }
+  }
+  Ok(bounds)
+}
+
 fn get_repr(attributes: &[Attribute]) -> Result<Representation> {
   attributes
     .iter()
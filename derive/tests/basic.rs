@@ -1,7 +1,7 @@
 #![allow(dead_code)]
 
 use bytemuck::{
-  AnyBitPattern, Contiguous, CheckedBitPattern, NoUninit, Pod, TransparentWrapper, Zeroable,
+  AnyBitPattern, ByteEq, Contiguous, ContiguousTryFrom, CheckedBitPattern, NoUninit, Pod, TransparentWrapper, Zeroable,
 };
 use std::marker::PhantomData;
 
@@ -63,6 +63,25 @@ struct TransparentWithZeroSized<T> {
   b: PhantomData<T>,
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, TransparentWrapper)]
+#[repr(transparent)]
+struct TransparentGenericWithZeroSized<T>(T, PhantomData<u8>);
+
+#[derive(TransparentWrapper)]
+#[repr(transparent)]
+struct TransparentGenericWithMarkerPath<T>(
+  core::marker::PhantomPinned,
+  T,
+  ::std::marker::PhantomData<u8>,
+);
+
+#[derive(TransparentWrapper)]
+#[repr(transparent)]
+struct TransparentGenericWithLifetime<'a, T, const N: usize> {
+  a: PhantomData<&'a ()>,
+  b: [T; N],
+}
+
 #[repr(u8)]
 #[derive(Clone, Copy, Contiguous)]
 enum ContiguousWithValues {
@@ -90,6 +109,25 @@ struct NoUninitTest {
   b: u16,
 }
 
+#[derive(Debug, Copy, Clone, NoUninit)]
+#[repr(C)]
+struct NoUninitRecord {
+  flag: bool,
+  kind: CheckedBitPatternEnumWithValues,
+  len: u16,
+}
+
+#[derive(Debug, Copy, Clone, NoUninit)]
+#[repr(transparent)]
+struct NoUninitTransparent(NoUninitRecord);
+
+#[derive(Debug, Copy, Clone, NoUninit)]
+#[repr(transparent)]
+struct NoUninitTransparentGeneric<T: NoUninit> {
+  inner: T,
+  marker: PhantomData<u32>,
+}
+
 #[derive(Copy, Clone, AnyBitPattern)]
 #[repr(C)]
 union UnionTestAnyBitPattern {
@@ -180,6 +218,49 @@ fn passes_cast_struct() {
   assert_eq!(*res, CheckedBitPatternStruct { a: 0, b: CheckedBitPatternEnumNonContiguous::B });
 }
 
+#[derive(Debug, Copy, Clone, NoUninit, CheckedBitPattern, PartialEq, Eq)]
+#[repr(C)]
+#[checked_bit_pattern(verbose)]
+struct CheckedBitPatternRecord {
+  id: u16,
+  first: CheckedBitPatternEnumWithValues,
+  second: CheckedBitPatternEnumNonContiguous,
+  flags: u32,
+}
+
+#[test]
+fn verbose_cast_struct_reports_field() {
+  #[repr(C, align(4))]
+  struct Aligned([u8; 8]);
+  let mut bytes = Aligned([1, 0, 2, 99, 7, 0, 0, 0]);
+  let err = bytemuck::checked::try_from_bytes_verbose::<CheckedBitPatternRecord>(&bytes.0)
+    .unwrap_err();
+  assert_eq!(
+    err,
+    bytemuck::checked::VerboseCheckedCastError::InvalidField(
+      bytemuck::checked::FieldError { field: Some("second"), offset: 3 }
+    )
+  );
+
+  bytes.0[3] = 56;
+  let res = bytemuck::checked::try_from_bytes_verbose::<CheckedBitPatternRecord>(&bytes.0)
+    .unwrap();
+  assert_eq!(res.second, CheckedBitPatternEnumNonContiguous::E);
+}
+
+#[test]
+fn verbose_cast_struct_without_attribute() {
+  let pod = [0u8, 24u8];
+  let err = bytemuck::checked::try_from_bytes_verbose::<CheckedBitPatternStruct>(&pod)
+    .unwrap_err();
+  assert_eq!(
+    err,
+    bytemuck::checked::VerboseCheckedCastError::InvalidField(
+      bytemuck::checked::FieldError { field: None, offset: 0 }
+    )
+  );
+}
+
 #[test]
 fn anybitpattern_implies_zeroable() {
   let test = AnyBitPatternTest::zeroed();
@@ -189,3 +270,135 @@ fn anybitpattern_implies_zeroable() {
 #[derive(Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
 #[repr(C, align(16))]
 struct Issue127 {}
+
+// Without the custom bound this fails, since the derive can't know that `T`
+// is `Zeroable`.
+#[derive(Debug, PartialEq, Zeroable)]
+#[zeroable(bound = "T: Zeroable")]
+struct ZeroableWithBound<T> {
+  a: T,
+  b: [T; 2],
+}
+
+#[test]
+fn derive_zeroable_custom_bound() {
+  let z = ZeroableWithBound::<u16>::zeroed();
+  assert_eq!(z, ZeroableWithBound { a: 0, b: [0, 0] });
+}
+
+#[test]
+fn derive_transparent_generic() {
+  type Tagged<T> = TransparentGenericWithZeroSized<T>;
+
+  let tagged: Tagged<u32> = Tagged::wrap(5);
+  assert_eq!(tagged, TransparentGenericWithZeroSized(5, PhantomData));
+  assert_eq!(Tagged::peel(tagged), 5);
+
+  let mut x = 7_u64;
+  *Tagged::wrap_mut(&mut x) = TransparentGenericWithZeroSized(8, PhantomData);
+  assert_eq!(x, 8);
+  assert_eq!(Tagged::peel_ref(Tagged::wrap_ref(&x)), &8);
+
+  let arr = [1_u8, 2, 3];
+  let w = TransparentGenericWithLifetime::wrap_ref(&arr);
+  assert_eq!(&w.b, &arr);
+}
+
+#[test]
+fn derive_no_uninit_transparent() {
+  let record = NoUninitRecord {
+    flag: true,
+    kind: CheckedBitPatternEnumWithValues::D,
+    len: 0x0102,
+  };
+  let expected = [&[1_u8, 3][..], &0x0102_u16.to_ne_bytes()[..]].concat();
+
+  assert_eq!(bytemuck::bytes_of(&NoUninitTransparent(record)), &expected[..]);
+  let generic = NoUninitTransparentGeneric { inner: record, marker: PhantomData };
+  assert_eq!(bytemuck::bytes_of(&generic), &expected[..]);
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Pod, Zeroable)]
+#[repr(C)]
+struct GridCell {
+  kind: u16,
+  flags: u8,
+  level: u8,
+}
+
+#[derive(Copy, Clone, Pod, Zeroable)]
+#[repr(C)]
+struct Grid {
+  width: u32,
+  height: u32,
+  cells: [GridCell; 100],
+  rows: [[u16; 3]; 10],
+}
+
+#[test]
+fn derive_pod_nested_array_of_structs() {
+  let mut grid: Grid = bytemuck::Zeroable::zeroed();
+  grid.width = 10;
+  grid.height = 10;
+  grid.cells[99] = GridCell { kind: 7, flags: 1, level: 2 };
+  grid.rows[9][2] = 0xABCD;
+
+  let bytes = bytemuck::bytes_of(&grid);
+  assert_eq!(bytes.len(), 4 + 4 + 100 * 4 + 10 * 3 * 2);
+  let cells: &[GridCell] = bytemuck::cast_slice(&bytes[8..408]);
+  assert_eq!(cells[99], GridCell { kind: 7, flags: 1, level: 2 });
+  assert_eq!(bytes[406..408], [1, 2]);
+  assert_eq!(bytes[bytes.len() - 2..], 0xABCD_u16.to_ne_bytes());
+}
+
+#[derive(Debug, Copy, Clone, NoUninit, ByteEq)]
+#[repr(C)]
+struct ByteEqTest {
+  a: u32,
+  b: [u16; 2],
+  c: u64,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
+struct FieldEqTest {
+  a: u32,
+  b: [u16; 2],
+  c: u64,
+}
+
+#[test]
+fn derive_byte_eq() {
+  let values = [(1, [2, 3], 4), (1, [2, 3], 5), (0, [0, 0], 0), (1, [3, 2], 4)];
+  for &(a1, b1, c1) in values.iter() {
+    for &(a2, b2, c2) in values.iter() {
+      let byte_eq = ByteEqTest { a: a1, b: b1, c: c1 }
+        == ByteEqTest { a: a2, b: b2, c: c2 };
+      let field_eq = FieldEqTest { a: a1, b: b1, c: c1 }
+        == FieldEqTest { a: a2, b: b2, c: c2 };
+      assert_eq!(byte_eq, field_eq);
+    }
+  }
+}
+
+#[repr(i8)]
+#[derive(Debug, Clone, Copy, PartialEq, Contiguous, ContiguousTryFrom)]
+enum ContiguousTryFromTest {
+  A = -1,
+  B,
+  C,
+}
+
+#[test]
+fn derive_contiguous_try_from() {
+  use bytemuck::ContiguousOutOfRange;
+  use std::convert::{TryFrom, TryInto};
+
+  assert_eq!(ContiguousTryFromTest::try_from(-1), Ok(ContiguousTryFromTest::A));
+  assert_eq!(1_i8.try_into(), Ok(ContiguousTryFromTest::C));
+
+  let err = ContiguousTryFromTest::try_from(2).unwrap_err();
+  assert_eq!(err, ContiguousOutOfRange { value: 2, min: -1, max: 1 });
+  assert_eq!(err.to_string(), "2 is out of the range -1..=1");
+  assert!(ContiguousTryFromTest::try_from(i8::min_value()).is_err());
+}
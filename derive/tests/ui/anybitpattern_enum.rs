@@ -0,0 +1,10 @@
+use bytemuck::AnyBitPattern;
+
+#[derive(Clone, Copy, AnyBitPattern)]
+#[repr(u8)]
+enum Fieldless {
+  A = 0,
+  B = 1,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bytemuck::ByteEq;
+
+// `ByteEq` alone, on a struct that has a padding byte after `a`.
+#[derive(Clone, Copy, ByteEq)]
+#[repr(C)]
+struct Padded {
+  a: u8,
+  b: u32,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bytemuck::{CheckedBitPattern, NoUninit};
+
+#[derive(Clone, Copy, NoUninit, CheckedBitPattern)]
+#[repr(C)]
+#[checked_bit_pattern(loud)]
+struct Record {
+  id: u32,
+  letter: char,
+}
+
+fn main() {}
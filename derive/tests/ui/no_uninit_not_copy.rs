@@ -0,0 +1,9 @@
+use bytemuck::NoUninit;
+
+// `NoUninit` has `Copy` as a supertrait, so a non-`Copy` wrapper is rejected
+// even when the inner type is `NoUninit`.
+#[derive(NoUninit)]
+#[repr(transparent)]
+struct Wrapper(u32);
+
+fn main() {}
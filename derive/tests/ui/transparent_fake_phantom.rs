@@ -0,0 +1,13 @@
+use bytemuck::TransparentWrapper;
+
+// Not `core::marker::PhantomData`, and not zero-sized.
+mod fake {
+  #[derive(Clone, Copy)]
+  pub struct PhantomData<T>(pub T);
+}
+
+#[derive(TransparentWrapper)]
+#[repr(transparent)]
+struct Wrapper<T>(T, fake::PhantomData<()>);
+
+fn main() {}
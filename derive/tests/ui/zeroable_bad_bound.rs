@@ -0,0 +1,9 @@
+use bytemuck::Zeroable;
+
+#[derive(Zeroable)]
+#[zeroable(bound = "T Zeroable")]
+struct Wrapper<T> {
+  a: T,
+}
+
+fn main() {}
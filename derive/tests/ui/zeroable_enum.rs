@@ -0,0 +1,10 @@
+use bytemuck::Zeroable;
+
+#[derive(Clone, Copy, Zeroable)]
+#[repr(u8)]
+enum Fieldless {
+  A = 0,
+  B = 1,
+}
+
+fn main() {}
@@ -10,18 +10,23 @@
 //! ["extern_crate_alloc"]}`
 
 use super::*;
+use crate::{checked::CheckedCastError, internal::something_went_wrong};
 use alloc::{
   alloc::{alloc_zeroed, Layout},
+  borrow::Cow,
   boxed::Box,
   rc::Rc,
   sync::Arc,
   vec,
   vec::Vec,
 };
+#[cfg(feature = "nightly_allocator_api")]
+use core::alloc::Allocator;
 use core::convert::TryInto;
 
 /// As [`try_cast_box`](try_cast_box), but unwraps for you.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_box<A: NoUninit, B: AnyBitPattern>(input: Box<A>) -> Box<B> {
   try_cast_box(input).map_err(|(e, _v)| e).unwrap()
 }
@@ -50,6 +55,10 @@ pub fn try_cast_box<A: NoUninit, B: AnyBitPattern>(
   }
 }
 
+/// The panic message of the panicking versions of the `zeroed` functions.
+const ALLOC_FAILED: &str =
+  "the allocation failed, or was more than isize::MAX bytes";
+
 /// Allocates a `Box<T>` with all of the contents being zeroed out.
 ///
 /// This uses the global allocator to create a zeroed allocation and _then_
@@ -91,8 +100,47 @@ pub fn try_zeroed_box<T: Zeroable>() -> Result<Box<T>, ()> {
 
 /// As [`try_zeroed_box`], but unwraps for you.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn zeroed_box<T: Zeroable>() -> Box<T> {
-  try_zeroed_box().unwrap()
+  match try_zeroed_box() {
+    Ok(b) => b,
+    Err(()) => something_went_wrong("zeroed_box", ALLOC_FAILED),
+  }
+}
+
+/// As [`try_zeroed_box`], but allocates from the given allocator rather than
+/// the global one.
+///
+/// This needs the `nightly_allocator_api` feature, and so requires nightly.
+///
+/// ## Failure
+///
+/// This fails if the allocation fails.
+#[cfg(feature = "nightly_allocator_api")]
+#[inline]
+pub fn try_zeroed_box_in<T: Zeroable, A: Allocator>(
+  alloc: A,
+) -> Result<Box<T, A>, ()> {
+  if size_of::<T>() == 0 {
+    // Note: a ZST is never put on the stack "for real", and `Box` won't ask the
+    // allocator for any memory.
+    return Ok(Box::new_in(T::zeroed(), alloc));
+  }
+  match alloc.allocate_zeroed(Layout::new::<T>()) {
+    Ok(ptr) => Ok(unsafe { Box::from_raw_in(ptr.as_ptr() as *mut T, alloc) }),
+    Err(_) => Err(()),
+  }
+}
+
+/// As [`try_zeroed_box_in`], but unwraps for you.
+#[cfg(feature = "nightly_allocator_api")]
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn zeroed_box_in<T: Zeroable, A: Allocator>(alloc: A) -> Box<T, A> {
+  match try_zeroed_box_in(alloc) {
+    Ok(b) => b,
+    Err(()) => something_went_wrong("zeroed_box_in", ALLOC_FAILED),
+  }
 }
 
 /// Allocates a `Vec<T>` of length and capacity exactly equal to `length` and
@@ -101,7 +149,8 @@ pub fn zeroed_box<T: Zeroable>() -> Box<T> {
 /// ## Failure
 ///
 /// This fails if the allocation fails, or if a layout cannot be calculated for
-/// the allocation.
+/// the allocation (which includes when `length * size_of::<T>()` would be more
+/// than `isize::MAX` bytes).
 pub fn try_zeroed_vec<T: Zeroable>(length: usize) -> Result<Vec<T>, ()> {
   if length == 0 {
     Ok(Vec::new())
@@ -112,8 +161,52 @@ pub fn try_zeroed_vec<T: Zeroable>(length: usize) -> Result<Vec<T>, ()> {
 }
 
 /// As [`try_zeroed_vec`] but unwraps for you
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn zeroed_vec<T: Zeroable>(length: usize) -> Vec<T> {
-  try_zeroed_vec(length).unwrap()
+  match try_zeroed_vec(length) {
+    Ok(v) => v,
+    Err(()) => something_went_wrong("zeroed_vec", ALLOC_FAILED),
+  }
+}
+
+/// As [`try_zeroed_vec`], but allocates from the given allocator rather than
+/// the global one.
+///
+/// This needs the `nightly_allocator_api` feature, and so requires nightly.
+///
+/// ## Failure
+///
+/// This fails if the allocation fails, or if a layout cannot be calculated for
+/// the allocation.
+#[cfg(feature = "nightly_allocator_api")]
+pub fn try_zeroed_vec_in<T: Zeroable, A: Allocator>(
+  length: usize, alloc: A,
+) -> Result<Vec<T, A>, ()> {
+  if size_of::<T>() == 0 || length == 0 {
+    // This will not allocate.
+    let mut vec = Vec::with_capacity_in(length, alloc);
+    vec.resize_with(length, || T::zeroed());
+    return Ok(vec);
+  }
+  let layout = Layout::array::<T>(length).map_err(|_| ())?;
+  match alloc.allocate_zeroed(layout) {
+    Ok(ptr) => Ok(unsafe {
+      Vec::from_raw_parts_in(ptr.as_ptr() as *mut T, length, length, alloc)
+    }),
+    Err(_) => Err(()),
+  }
+}
+
+/// As [`try_zeroed_vec_in`] but unwraps for you
+#[cfg(feature = "nightly_allocator_api")]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn zeroed_vec_in<T: Zeroable, A: Allocator>(
+  length: usize, alloc: A,
+) -> Vec<T, A> {
+  match try_zeroed_vec_in(length, alloc) {
+    Ok(v) => v,
+    Err(()) => something_went_wrong("zeroed_vec_in", ALLOC_FAILED),
+  }
 }
 
 /// Allocates a `Box<[T]>` with all contents being zeroed out.
@@ -126,7 +219,8 @@ pub fn zeroed_vec<T: Zeroable>(length: usize) -> Vec<T> {
 /// ## Failure
 ///
 /// This fails if the allocation fails, or if a layout cannot be calculated for
-/// the allocation.
+/// the allocation (which includes when `length * size_of::<T>()` would be more
+/// than `isize::MAX` bytes).
 #[inline]
 pub fn try_zeroed_slice_box<T: Zeroable>(
   length: usize,
@@ -154,12 +248,17 @@ pub fn try_zeroed_slice_box<T: Zeroable>(
 }
 
 /// As [`try_zeroed_slice_box`](try_zeroed_slice_box), but unwraps for you.
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn zeroed_slice_box<T: Zeroable>(length: usize) -> Box<[T]> {
-  try_zeroed_slice_box(length).unwrap()
+  match try_zeroed_slice_box(length) {
+    Ok(b) => b,
+    Err(()) => something_went_wrong("zeroed_slice_box", ALLOC_FAILED),
+  }
 }
 
 /// As [`try_cast_slice_box`](try_cast_slice_box), but unwraps for you.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_slice_box<A: NoUninit, B: AnyBitPattern>(
   input: Box<[A]>,
 ) -> Box<[B]> {
@@ -211,6 +310,7 @@ pub fn try_cast_slice_box<A: NoUninit, B: AnyBitPattern>(
 
 /// As [`try_cast_vec`](try_cast_vec), but unwraps for you.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_vec<A: NoUninit, B: AnyBitPattern>(input: Vec<A>) -> Vec<B> {
   try_cast_vec(input).map_err(|(e, _v)| e).unwrap()
 }
@@ -298,6 +398,11 @@ pub fn try_cast_vec<A: NoUninit, B: AnyBitPattern>(
 ///   assert_eq!(&vec_of_words[..], &[0x0005_0006, 0x0007_0008][..])
 /// }
 /// ```
+///
+/// ## Panics
+///
+/// * If `B` is a ZST and `src` isn't empty.
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn pod_collect_to_vec<
   A: NoUninit + AnyBitPattern,
   B: NoUninit + AnyBitPattern,
@@ -305,6 +410,13 @@ pub fn pod_collect_to_vec<
   src: &[A],
 ) -> Vec<B> {
   let src_size = size_of_val(src);
+  if size_of::<B>() == 0 {
+    // No number of ZSTs can hold the bytes, and we can't divide by zero.
+    if src_size == 0 {
+      return Vec::new();
+    }
+    something_went_wrong("pod_collect_to_vec", PodCastError::SizeMismatch);
+  }
   // Note(Lokathor): dst_count is rounded up so that the dest will always be at
   // least as many bytes as the src.
   let dst_count = src_size / size_of::<B>()
@@ -317,8 +429,128 @@ pub fn pod_collect_to_vec<
   dst
 }
 
+/// Reads pod values out of a sequence of byte chunks, as if the chunks were
+/// one contiguous buffer.
+///
+/// This is for when the bytes arrive in pieces (eg: network packets), so that
+/// you don't have to concatenate them first. A value may be split across any
+/// number of chunks.
+///
+/// ```rust
+/// # use bytemuck::*;
+/// let chunks: [&[u8]; 3] = [&[1, 0, 0], &[0, 2], &[0, 0, 0]];
+/// let words: Vec<u32> = pod_collect_chunks_to_vec(&chunks).unwrap();
+/// assert_eq!(
+///   words,
+///   [u32::from_ne_bytes([1, 0, 0, 0]), u32::from_ne_bytes([2, 0, 0, 0])]
+/// );
+/// ```
+///
+/// ## Failure
+///
+/// * If the total number of bytes isn't a multiple of the size of `T`. A ZST
+///   `T` is only allowed if there are no bytes at all.
+pub fn pod_collect_chunks_to_vec<T, I>(
+  chunks: I,
+) -> Result<Vec<T>, PodCastError>
+where
+  T: AnyBitPattern,
+  I: IntoIterator,
+  I::Item: AsRef<[u8]>,
+{
+  let mut dst: Vec<T> = Vec::new();
+  let mut filled: usize = 0;
+  for chunk in chunks {
+    let bytes: &[u8] = chunk.as_ref();
+    if bytes.is_empty() {
+      continue;
+    }
+    if size_of::<T>() == 0 {
+      return Err(PodCastError::SizeMismatch);
+    }
+    let new_filled = filled + bytes.len();
+    let dst_count = new_filled / size_of::<T>()
+      + if new_filled % size_of::<T>() != 0 { 1 } else { 0 };
+    dst.resize(dst_count, T::zeroed());
+    // Safety: `dst` now holds at least `new_filled` bytes, and any bit pattern
+    // is valid for `T`. Writing through a raw pointer is fine even if `T`
+    // has padding bytes.
+    unsafe {
+      core::ptr::copy_nonoverlapping(
+        bytes.as_ptr(),
+        (dst[..].as_mut_ptr() as *mut u8).add(filled),
+        bytes.len(),
+      );
+    }
+    filled = new_filled;
+  }
+  if size_of::<T>() != 0 && filled % size_of::<T>() != 0 {
+    return Err(PodCastError::OutputSliceWouldHaveSlop);
+  }
+  Ok(dst)
+}
+
+/// Casts a slice, borrowing when possible and copying when it isn't.
+///
+/// If the input is aligned for `B` this is [`try_cast_slice`] and gives a
+/// `Cow::Borrowed`. Otherwise the bytes are copied into a new `Vec<B>` (which
+/// is always aligned) and you get a `Cow::Owned`. This is handy for loading
+/// data out of a byte buffer that may or may not be suitably aligned.
+///
+/// ```rust
+/// # use bytemuck::allocation::cast_slice_cow;
+/// # use std::borrow::Cow;
+/// let words = [1_u64, 2, 3];
+/// let bytes: &[u8] = bytemuck::cast_slice(&words);
+/// // the start of the array is aligned, so this borrows
+/// let all = cast_slice_cow::<u8, u64>(bytes).unwrap();
+/// assert!(matches!(all, Cow::Borrowed(_)));
+/// // 4 bytes in isn't aligned for `u64`, so this copies
+/// let middle = cast_slice_cow::<u8, u64>(&bytes[4..20]).unwrap();
+/// assert!(matches!(middle, Cow::Owned(_)));
+/// ```
+///
+/// ## Failure
+///
+/// * If the target type has a different size, and the input isn't a whole
+///   number of target elements (`OutputSliceWouldHaveSlop`), or only one of
+///   the types is a ZST (`SizeMismatch`). Alignment is never an error.
+pub fn cast_slice_cow<'a, A: NoUninit, B: AnyBitPattern>(
+  input: &'a [A],
+) -> Result<Cow<'a, [B]>, PodCastError> {
+  match try_cast_slice(input) {
+    Ok(b) => return Ok(Cow::Borrowed(b)),
+    Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned) => (),
+    Err(e) => return Err(e),
+  }
+  // Note: the alignment check comes first in `try_cast_slice`, so we still have
+  // to do the size checks here.
+  let input_size = size_of_val(input);
+  let dst_count = if size_of::<B>() == size_of::<A>() {
+    input.len()
+  } else if size_of::<A>() == 0 || size_of::<B>() == 0 {
+    return Err(PodCastError::SizeMismatch);
+  } else if input_size % size_of::<B>() == 0 {
+    input_size / size_of::<B>()
+  } else {
+    return Err(PodCastError::OutputSliceWouldHaveSlop);
+  };
+  let mut dst = vec![B::zeroed(); dst_count];
+  // Safety: `dst` holds exactly `input_size` bytes, the input is `NoUninit` so
+  // all of its bytes are initialized, and any bit pattern is valid for `B`.
+  unsafe {
+    core::ptr::copy_nonoverlapping(
+      input.as_ptr() as *const u8,
+      dst[..].as_mut_ptr() as *mut u8,
+      input_size,
+    );
+  }
+  Ok(Cow::Owned(dst))
+}
+
 /// As [`try_cast_rc`](try_cast_rc), but unwraps for you.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_rc<A: NoUninit + AnyBitPattern, B: NoUninit + AnyBitPattern>(
   input: Rc<A>,
 ) -> Rc<B> {
@@ -355,6 +587,7 @@ pub fn try_cast_rc<A: NoUninit + AnyBitPattern, B: NoUninit + AnyBitPattern>(
 
 /// As [`try_cast_arc`](try_cast_arc), but unwraps for you.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_arc<A: NoUninit + AnyBitPattern, B: NoUninit + AnyBitPattern>(
   input: Arc<A>,
 ) -> Arc<B> {
@@ -394,6 +627,7 @@ pub fn try_cast_arc<
 
 /// As [`try_cast_slice_rc`](try_cast_slice_rc), but unwraps for you.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_slice_rc<
   A: NoUninit + AnyBitPattern,
   B: NoUninit + AnyBitPattern,
@@ -456,6 +690,7 @@ pub fn try_cast_slice_rc<
 
 /// As [`try_cast_slice_arc`](try_cast_slice_arc), but unwraps for you.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_slice_arc<
   A: NoUninit + AnyBitPattern,
   B: NoUninit + AnyBitPattern,
@@ -516,6 +751,78 @@ pub fn try_cast_slice_arc<
   }
 }
 
+/// Serializes tuples field-by-field, in declaration order.
+///
+/// Rust doesn't guarantee anything about the layout of a tuple, so they can't
+/// be [`Pod`], and a tuple like `(u8, u32)` would have padding anyway. Instead,
+/// this trait writes out the bytes of each field one after the other, with no
+/// padding in between, so the output doesn't depend on the tuple's layout at
+/// all.
+///
+/// This is implemented for tuples of up to 8 elements, where every element is
+/// both [`NoUninit`] and [`CheckedBitPattern`].
+///
+/// ```rust
+/// # use bytemuck::PodTuple;
+/// let mut out = Vec::new();
+/// (1_u8, 2_u16).write_bytes(&mut out);
+/// assert_eq!(out.len(), 3);
+/// assert_eq!(<(u8, u16)>::read_bytes(&out), Ok((1, 2)));
+/// ```
+pub trait PodTuple: Sized {
+  /// The total number of bytes that [`write_bytes`](PodTuple::write_bytes)
+  /// appends, and that [`read_bytes`](PodTuple::read_bytes) expects.
+  const BYTE_LEN: usize;
+
+  /// Appends the bytes of each field, in order, to `out`.
+  fn write_bytes(&self, out: &mut Vec<u8>);
+
+  /// Reads each field, in order, from `bytes`.
+  ///
+  /// ## Failure
+  ///
+  /// * If the `bytes` length is not equal to [`BYTE_LEN`](PodTuple::BYTE_LEN).
+  /// * If any field's bytes are an invalid bit pattern for that field.
+  fn read_bytes(bytes: &[u8]) -> Result<Self, CheckedCastError>;
+}
+
+macro_rules! impl_pod_tuple {
+  ($($field:tt $T:ident),+) => {
+    impl<$($T: NoUninit + CheckedBitPattern),+> PodTuple for ($($T,)+) {
+      const BYTE_LEN: usize = 0 $( + size_of::<$T>() )+;
+
+      #[inline]
+      fn write_bytes(&self, out: &mut Vec<u8>) {
+        out.reserve(Self::BYTE_LEN);
+        $( out.extend_from_slice(bytes_of(&self.$field)); )+
+      }
+
+      #[inline]
+      #[allow(unused_assignments)]
+      fn read_bytes(bytes: &[u8]) -> Result<Self, CheckedCastError> {
+        if bytes.len() != Self::BYTE_LEN {
+          return Err(PodCastError::SizeMismatch.into());
+        }
+        let mut rest = bytes;
+        Ok(($({
+          let (head, tail) = rest.split_at(size_of::<$T>());
+          rest = tail;
+          checked::try_pod_read_unaligned::<$T>(head)?
+        },)+))
+      }
+    }
+  };
+}
+
+impl_pod_tuple!(0 A);
+impl_pod_tuple!(0 A, 1 B);
+impl_pod_tuple!(0 A, 1 B, 2 C);
+impl_pod_tuple!(0 A, 1 B, 2 C, 3 D);
+impl_pod_tuple!(0 A, 1 B, 2 C, 3 D, 4 E);
+impl_pod_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+impl_pod_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+impl_pod_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+
 /// An extension trait for `TransparentWrapper` and alloc types.
 pub trait TransparentWrapperAlloc<Inner: ?Sized>:
   TransparentWrapper<Inner>
@@ -26,8 +26,8 @@ use crate::{Pod, Zeroable};
 /// below safety rules.
 ///
 /// * *NOTE: even `C-style`, fieldless enums are intentionally **excluded** from
-/// this trait, since it is **unsound** for an enum to have a discriminant value
-/// that is not one of its defined variants.
+///   this trait, since it is **unsound** for an enum to have a discriminant
+///   value that is not one of its defined variants.
 ///
 /// # Safety
 ///
@@ -0,0 +1,87 @@
+use super::*;
+
+/// Types that can have their byte order reversed.
+///
+/// This is implemented for the primitive integer and float types, and (with
+/// the `min_const_generics` feature) for arrays of those. A number's bytes are
+/// reversed, the same as its inherent `swap_bytes` method. An array has each
+/// of its elements swapped on its own, with the elements staying in the same
+/// order.
+///
+/// The method swaps in place and has its own name, so that it's never
+/// confused with the inherent `swap_bytes`, which returns a new value.
+///
+/// ```rust
+/// # use bytemuck::ByteSwap;
+/// let mut x = 0x1234_u16;
+/// x.byte_swap_in_place();
+/// assert_eq!(x, 0x3412);
+///
+/// let mut f = 1.0_f32;
+/// f.byte_swap_in_place();
+/// assert_eq!(f.to_bits(), 0x0000_803F);
+/// ```
+pub trait ByteSwap: Pod {
+  /// Reverses the byte order of the value, in place.
+  fn byte_swap_in_place(&mut self);
+}
+
+macro_rules! impl_byte_swap_for_int {
+  ($($t:ty),* $(,)?) => {
+    $(
+      impl ByteSwap for $t {
+        #[inline]
+        fn byte_swap_in_place(&mut self) {
+          *self = <$t>::swap_bytes(*self);
+        }
+      }
+    )*
+  };
+}
+impl_byte_swap_for_int!(
+  u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize
+);
+
+impl ByteSwap for f32 {
+  #[inline]
+  fn byte_swap_in_place(&mut self) {
+    *self = f32::from_bits(self.to_bits().swap_bytes());
+  }
+}
+impl ByteSwap for f64 {
+  #[inline]
+  fn byte_swap_in_place(&mut self) {
+    *self = f64::from_bits(self.to_bits().swap_bytes());
+  }
+}
+
+#[cfg(feature = "min_const_generics")]
+impl<T: ByteSwap, const N: usize> ByteSwap for [T; N] {
+  #[inline]
+  fn byte_swap_in_place(&mut self) {
+    swap_endianness_slice(self);
+  }
+}
+
+/// Reverses the byte order of each element of the slice, in place.
+///
+/// Each element is swapped on its own, and the elements stay in the same order.
+/// This is different from reversing the bytes of the whole slice, which would
+/// also reverse the order of the elements. For 1-byte elements there's nothing
+/// to do.
+///
+/// ```rust
+/// # use bytemuck::swap_endianness_slice;
+/// let mut words = [0x0102_0304_u32, 0x0506_0708];
+/// swap_endianness_slice(&mut words);
+/// assert_eq!(words, [0x0403_0201, 0x0807_0605]);
+/// ```
+#[inline]
+pub fn swap_endianness_slice<T: ByteSwap>(slice: &mut [T]) {
+  if size_of::<T>() <= 1 {
+    return;
+  }
+  for t in slice.iter_mut() {
+    t.byte_swap_in_place();
+  }
+}
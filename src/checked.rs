@@ -115,12 +115,12 @@ use crate::{
 /// # Safety
 ///
 /// * `Self` *must* have the same layout as the specified `Bits` except for
-/// the possible invalid bit patterns being checked during
-/// [`is_valid_bit_pattern`].
-///   * This almost certainly means your type must be `#[repr(C)]` or a similar
-///   specified repr, but if you think you know better, you probably don't. If
-/// you   still think you know better, be careful and have fun. And don't mess
-/// it up   (I mean it).
+///   the possible invalid bit patterns being checked during
+///   [`is_valid_bit_pattern`].
+///   * This almost certainly means your type must be `#[repr(C)]` or a
+///     similar specified repr, but if you think you know better, you probably
+///     don't. If you still think you know better, be careful and have fun. And
+///     don't mess it up (I mean it).
 /// * If [`is_valid_bit_pattern`] returns true, then the bit pattern contained
 ///   in `bits` must also be valid for an instance of `Self`.
 /// * Probably more, don't mess it up (I mean it 2.0)
@@ -138,6 +138,23 @@ pub unsafe trait CheckedBitPattern: Copy {
   /// If this function returns true, then it must be valid to reinterpret `bits`
   /// as `&Self`.
   fn is_valid_bit_pattern(bits: &Self::Bits) -> bool;
+
+  /// As [`is_valid_bit_pattern`], but on failure says which field was invalid.
+  ///
+  /// The default just reports the whole value (a `field` of `None` at offset
+  /// 0). The derive overrides this for structs to name the first invalid
+  /// field. This is only used for error reporting, never to decide if a value
+  /// is valid.
+  ///
+  /// [`is_valid_bit_pattern`]: CheckedBitPattern::is_valid_bit_pattern
+  #[inline]
+  fn validate_verbose(bits: &Self::Bits) -> Result<(), FieldError> {
+    if Self::is_valid_bit_pattern(bits) {
+      Ok(())
+    } else {
+      Err(FieldError { field: None, offset: 0 })
+    }
+  }
 }
 
 unsafe impl<T: AnyBitPattern> CheckedBitPattern for T {
@@ -170,6 +187,181 @@ unsafe impl CheckedBitPattern for bool {
   }
 }
 
+macro_rules! impl_checked_for_nonzero {
+  ($($nonzero:ty: $primitive:ty),* $(,)?) => {
+    $(
+      unsafe impl CheckedBitPattern for $nonzero {
+        type Bits = $primitive;
+
+        #[inline]
+        fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
+          *bits != 0
+        }
+      }
+    )*
+  };
+}
+impl_checked_for_nonzero! {
+  core::num::NonZeroU8: u8,
+  core::num::NonZeroI8: i8,
+  core::num::NonZeroU16: u16,
+  core::num::NonZeroI16: i16,
+  core::num::NonZeroU32: u32,
+  core::num::NonZeroI32: i32,
+  core::num::NonZeroU64: u64,
+  core::num::NonZeroI64: i64,
+  core::num::NonZeroI128: i128,
+  core::num::NonZeroU128: u128,
+  core::num::NonZeroUsize: usize,
+  core::num::NonZeroIsize: isize,
+}
+
+/// The [`CheckedBitPattern::Bits`] of a [`Bound<T>`](core::ops::Bound).
+///
+/// This is the layout that current compilers give to `Bound<T>` for the
+/// integer and float types it's implemented for: the tag, widened to an
+/// unsigned integer as big as the value, and then the value (which is only
+/// meaningful for `Included` and `Excluded`). Neither field leaves any padding.
+#[cfg(feature = "unsound_bound_checked_impl")]
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct BoundBits<Tag, T> {
+  /// The variant's discriminant.
+  pub tag: Tag,
+  /// The bound's value.
+  pub value: T,
+}
+#[cfg(feature = "unsound_bound_checked_impl")]
+unsafe impl<Tag: crate::Pod, T: crate::Pod> crate::Zeroable
+  for BoundBits<Tag, T>
+{
+}
+#[cfg(feature = "unsound_bound_checked_impl")]
+unsafe impl<Tag: crate::Pod, T: crate::Pod> AnyBitPattern
+  for BoundBits<Tag, T>
+{
+}
+
+/// Checks that `Bound<T>` is laid out like `BoundBits<Tag, T>`, and finds the
+/// tags of `Included`, `Excluded`, and `Unbounded` in that order.
+///
+/// Gives `None` if the size, the alignment, or the offset of the value don't
+/// match, or if the tags aren't three different values below 256.
+#[cfg(feature = "unsound_bound_checked_impl")]
+fn probe_bound_tags<Tag, T>() -> Option<[u8; 3]>
+where
+  Tag: crate::Pod + From<u8> + PartialEq,
+  T: crate::Pod,
+{
+  use core::{
+    mem::{align_of, size_of},
+    ops::Bound,
+  };
+  if size_of::<Bound<T>>() != size_of::<BoundBits<Tag, T>>()
+    || align_of::<Bound<T>>() != align_of::<BoundBits<Tag, T>>()
+  {
+    return None;
+  }
+  let value_offset = {
+    let bits = BoundBits { tag: Tag::zeroed(), value: T::zeroed() };
+    &bits.value as *const T as usize
+      - &bits as *const BoundBits<Tag, T> as usize
+  };
+  let mut value = T::zeroed();
+  for b in crate::bytes_of_mut(&mut value) {
+    *b = 0xA5;
+  }
+  let value_is_at_offset = |b: &Bound<T>| {
+    let p = b as *const Bound<T> as *const u8;
+    (0..size_of::<T>()).all(|i| unsafe { *p.add(value_offset + i) } == 0xA5)
+  };
+  let included = Bound::Included(value);
+  let excluded = Bound::Excluded(value);
+  if !value_is_at_offset(&included) || !value_is_at_offset(&excluded) {
+    return None;
+  }
+  // Note: this relies on the tag being widened to fill `Tag`, so that all of
+  // its bytes are initialized.
+  let tag_of = |b: &Bound<T>| {
+    let tag = unsafe { *(b as *const Bound<T> as *const Tag) };
+    (0..=u8::max_value()).find(|&t| Tag::from(t) == tag)
+  };
+  let tags =
+    [tag_of(&included)?, tag_of(&excluded)?, tag_of(&Bound::Unbounded)?];
+  if tags[0] == tags[1] || tags[0] == tags[2] || tags[1] == tags[2] {
+    return None;
+  }
+  Some(tags)
+}
+
+/// [`probe_bound_tags`], done once per type and then kept in `cache`.
+///
+/// ## Panics
+/// * If the layout of `Bound<T>` doesn't match `BoundBits<Tag, T>`.
+#[cfg(feature = "unsound_bound_checked_impl")]
+fn bound_tags<Tag, T>(cache: &core::sync::atomic::AtomicU32) -> [u8; 3]
+where
+  Tag: crate::Pod + From<u8> + PartialEq,
+  T: crate::Pod,
+{
+  use core::sync::atomic::Ordering;
+  // Note: 0 means "not probed yet", so the top byte is set once it has been.
+  let mut packed = cache.load(Ordering::Relaxed);
+  if packed == 0 {
+    let tags = match probe_bound_tags::<Tag, T>() {
+      Some(tags) => tags,
+      None => panic!(
+        "the layout of `Bound` doesn't match `BoundBits`, so the \
+         `unsound_bound_checked_impl` feature can't be used with this compiler"
+      ),
+    };
+    packed = 1 << 24
+      | u32::from(tags[0])
+      | u32::from(tags[1]) << 8
+      | u32::from(tags[2]) << 16;
+    cache.store(packed, Ordering::Relaxed);
+  }
+  [packed as u8, (packed >> 8) as u8, (packed >> 16) as u8]
+}
+
+// Note: the layout of `Bound<T>` isn't specified by the language, so these
+// assume the layout of `BoundBits`. The first check of each type compares that
+// against real `Bound` values, and panics if they differ.
+#[cfg(feature = "unsound_bound_checked_impl")]
+macro_rules! impl_checked_for_bound {
+  ($($value:ty: $tag:ty),* $(,)?) => {
+    $(
+      unsafe impl CheckedBitPattern for core::ops::Bound<$value> {
+        type Bits = BoundBits<$tag, $value>;
+
+        #[inline]
+        fn is_valid_bit_pattern(bits: &Self::Bits) -> bool {
+          static TAGS: core::sync::atomic::AtomicU32 =
+            core::sync::atomic::AtomicU32::new(0);
+          bound_tags::<$tag, $value>(&TAGS)
+            .iter()
+            .any(|&tag| bits.tag == <$tag>::from(tag))
+        }
+      }
+    )*
+  };
+}
+#[cfg(feature = "unsound_bound_checked_impl")]
+impl_checked_for_bound! {
+  u8: u8, i8: u8,
+  u16: u16, i16: u16,
+  u32: u32, i32: u32, f32: u32,
+}
+// Note: on some 32-bit targets `u64` only has an alignment of 4, which would
+// give `Bound<u64>` a narrower tag.
+#[cfg(all(
+  feature = "unsound_bound_checked_impl",
+  target_pointer_width = "64"
+))]
+impl_checked_for_bound! {
+  u64: u64, i64: u64, f64: u64,
+}
+
 /// The things that can go wrong when casting between [`CheckedBitPattern`] data
 /// forms.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -186,7 +378,12 @@ pub enum CheckedCastError {
 #[cfg(not(target_arch = "spirv"))]
 impl core::fmt::Display for CheckedCastError {
   fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-    write!(f, "{:?}", self)
+    match self {
+      CheckedCastError::PodCastError(e) => core::fmt::Display::fmt(e, f),
+      CheckedCastError::InvalidBitPattern => {
+        f.write_str("the input is an invalid bit pattern for the target type")
+      }
+    }
   }
 }
 #[cfg(feature = "extern_crate_std")]
@@ -198,6 +395,76 @@ impl From<crate::PodCastError> for CheckedCastError {
   }
 }
 
+/// Where in a value an invalid bit pattern was found.
+///
+/// See [`CheckedBitPattern::validate_verbose`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldError {
+  /// The name of the field (or its index for a tuple struct), or `None` if
+  /// the type doesn't break the error down by field.
+  pub field: Option<&'static str>,
+  /// The byte offset of the field within the value.
+  pub offset: usize,
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::fmt::Display for FieldError {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    match self.field {
+      Some(field) => write!(
+        f,
+        "invalid bit pattern in field `{}` (at byte offset {})",
+        field, self.offset
+      ),
+      None => write!(f, "invalid bit pattern"),
+    }
+  }
+}
+#[cfg(feature = "extern_crate_std")]
+impl std::error::Error for FieldError {}
+
+/// As [`CheckedCastError`], but an invalid bit pattern says where it was.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VerboseCheckedCastError {
+  /// An error occurred during a true-[`Pod`] cast
+  ///
+  /// [`Pod`]: crate::Pod
+  PodCastError(crate::PodCastError),
+  /// The data contained an invalid bit pattern, found at this field.
+  InvalidField(FieldError),
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl core::fmt::Display for VerboseCheckedCastError {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    match self {
+      VerboseCheckedCastError::PodCastError(e) => core::fmt::Display::fmt(e, f),
+      VerboseCheckedCastError::InvalidField(e) => core::fmt::Display::fmt(e, f),
+    }
+  }
+}
+#[cfg(feature = "extern_crate_std")]
+impl std::error::Error for VerboseCheckedCastError {}
+
+impl From<crate::PodCastError> for VerboseCheckedCastError {
+  fn from(err: crate::PodCastError) -> VerboseCheckedCastError {
+    VerboseCheckedCastError::PodCastError(err)
+  }
+}
+
+impl From<VerboseCheckedCastError> for CheckedCastError {
+  fn from(err: VerboseCheckedCastError) -> CheckedCastError {
+    match err {
+      VerboseCheckedCastError::PodCastError(e) => {
+        CheckedCastError::PodCastError(e)
+      }
+      VerboseCheckedCastError::InvalidField(_) => {
+        CheckedCastError::InvalidBitPattern
+      }
+    }
+  }
+}
+
 /// Re-interprets `&[u8]` as `&T`.
 ///
 /// ## Failure
@@ -218,6 +485,36 @@ pub fn try_from_bytes<T: CheckedBitPattern>(
   }
 }
 
+/// Re-interprets `&[u8]` as `&T`, saying which field was invalid on failure.
+///
+/// This is [`try_from_bytes`], but an invalid bit pattern gives a
+/// [`FieldError`] from [`CheckedBitPattern::validate_verbose`], which for a
+/// derived struct names the first field that was invalid.
+///
+/// ## Failure
+///
+/// * If the slice isn't aligned for the new type
+/// * If the slice's length isn’t exactly the size of the new type
+/// * If the slice contains an invalid bit pattern for `T`
+#[inline]
+pub fn try_from_bytes_verbose<T: CheckedBitPattern>(
+  s: &[u8],
+) -> Result<&T, VerboseCheckedCastError> {
+  let pod: &<T as CheckedBitPattern>::Bits =
+    unsafe { internal::try_from_bytes(s) }?;
+
+  if <T as CheckedBitPattern>::is_valid_bit_pattern(pod) {
+    Ok(unsafe { &*(pod as *const <T as CheckedBitPattern>::Bits as *const T) })
+  } else {
+    // Note: only `is_valid_bit_pattern` is trusted for soundness, so a
+    // `validate_verbose` that disagrees with it still gives an error.
+    let field = <T as CheckedBitPattern>::validate_verbose(pod)
+      .err()
+      .unwrap_or(FieldError { field: None, offset: 0 });
+    Err(VerboseCheckedCastError::InvalidField(field))
+  }
+}
+
 /// Re-interprets `&mut [u8]` as `&mut T`.
 ///
 /// ## Failure
@@ -247,15 +544,50 @@ pub fn try_from_bytes_mut<T: CheckedBitPattern + NoUninit>(
 pub fn try_pod_read_unaligned<T: CheckedBitPattern>(
   bytes: &[u8],
 ) -> Result<T, CheckedCastError> {
-  let pod = unsafe { internal::try_pod_read_unaligned(bytes) }?;
+  let pod: <T as CheckedBitPattern>::Bits =
+    unsafe { internal::try_pod_read_unaligned(bytes) }?;
 
-  if <T as CheckedBitPattern>::is_valid_bit_pattern(pod) {
+  if <T as CheckedBitPattern>::is_valid_bit_pattern(&pod) {
     Ok(unsafe { transmute!(pod) })
   } else {
     Err(CheckedCastError::InvalidBitPattern)
   }
 }
 
+/// Reads the bytes as an `f32`, rejecting NaN and the infinities.
+///
+/// Every bit pattern is a valid `f32`, so this isn't about bit validity. It's
+/// for formats that don't allow non-finite floats to be stored at all. As with
+/// [`try_pod_read_unaligned`], alignment isn't a factor.
+///
+/// ## Failure
+///
+/// * If the `bytes` length is not equal to `size_of::<f32>()`.
+/// * If the value isn't finite, this gives
+///   [`InvalidBitPattern`](CheckedCastError::InvalidBitPattern).
+#[inline]
+pub fn try_read_finite_f32(bytes: &[u8]) -> Result<f32, CheckedCastError> {
+  let f: f32 = unsafe { internal::try_pod_read_unaligned(bytes) }?;
+  if f.is_finite() {
+    Ok(f)
+  } else {
+    Err(CheckedCastError::InvalidBitPattern)
+  }
+}
+
+/// Reads the bytes as an `f64`, rejecting NaN and the infinities.
+///
+/// As [`try_read_finite_f32`], but for `f64`.
+#[inline]
+pub fn try_read_finite_f64(bytes: &[u8]) -> Result<f64, CheckedCastError> {
+  let f: f64 = unsafe { internal::try_pod_read_unaligned(bytes) }?;
+  if f.is_finite() {
+    Ok(f)
+  } else {
+    Err(CheckedCastError::InvalidBitPattern)
+  }
+}
+
 /// Try to cast `T` into `U`.
 ///
 /// Note that for this particular type of cast, alignment isn't a factor. The
@@ -280,6 +612,41 @@ pub fn try_cast<A: NoUninit, B: CheckedBitPattern>(
   }
 }
 
+/// Try to cast `[A; N]` into `[B; N]`, checking each element.
+///
+/// As with [`try_cast`], alignment isn't a factor.
+///
+/// ## Failure
+///
+/// On failure you get back the index of the first element that failed along
+/// with the error.
+///
+/// * If `A` and `B` don't have the same size this fails (at index 0).
+/// * If any element of `a` contains an invalid bit pattern for `B` this fails.
+///
+/// As with the other functions in this module, the `try_` version returns a
+/// `Result` and [`cast_array`] is the version that panics. The error is paired
+/// with the index because [`CheckedCastError`] itself has no room for it.
+#[cfg(feature = "min_const_generics")]
+#[inline]
+pub fn try_cast_array<A: NoUninit, B: CheckedBitPattern, const N: usize>(
+  a: [A; N],
+) -> Result<[B; N], (usize, CheckedCastError)> {
+  if core::mem::size_of::<A>() != core::mem::size_of::<B>() {
+    return Err((0, crate::PodCastError::SizeMismatch.into()));
+  }
+  let pod: [<B as CheckedBitPattern>::Bits; N] =
+    unsafe { internal::try_cast(a) }.map_err(|e| (0, e.into()))?;
+
+  match pod
+    .iter()
+    .position(|b| !<B as CheckedBitPattern>::is_valid_bit_pattern(b))
+  {
+    Some(i) => Err((i, CheckedCastError::InvalidBitPattern)),
+    None => Ok(unsafe { transmute!(pod) }),
+  }
+}
+
 /// Try to convert a `&T` into `&U`.
 ///
 /// ## Failure
@@ -351,6 +718,46 @@ pub fn try_cast_slice<A: NoUninit, B: CheckedBitPattern>(
   }
 }
 
+/// Try to convert `&[A]` into `&[B]`, reporting which element was invalid.
+///
+/// This is [`try_cast_slice`], but on failure you get back the index (in the
+/// output slice) of the first element with an invalid bit pattern. That's
+/// handy for things like loading a table of `NonZeroU32` ids from bytes,
+/// where you want to know which entry was zero.
+///
+/// ```rust
+/// # use bytemuck::checked::{self, CheckedCastError};
+/// # use core::num::NonZeroU32;
+/// let ids: &[NonZeroU32] =
+///   checked::try_cast_slice_indexed(&[1_u32, 2, 3][..]).unwrap();
+/// assert_eq!(ids[2].get(), 3);
+///
+/// let bad = checked::try_cast_slice_indexed::<u32, NonZeroU32>(&[1, 0, 3]);
+/// assert_eq!(bad, Err((1, CheckedCastError::InvalidBitPattern)));
+/// ```
+///
+/// ## Failure
+///
+/// * Same as [`try_cast_slice`]. Errors that aren't about a particular element
+///   (such as alignment) are given with an index of 0.
+#[inline]
+pub fn try_cast_slice_indexed<A: NoUninit, B: CheckedBitPattern>(
+  a: &[A],
+) -> Result<&[B], (usize, CheckedCastError)> {
+  let pod =
+    unsafe { internal::try_cast_slice(a) }.map_err(|e| (0, e.into()))?;
+
+  match pod
+    .iter()
+    .position(|pod| !<B as CheckedBitPattern>::is_valid_bit_pattern(pod))
+  {
+    Some(i) => Err((i, CheckedCastError::InvalidBitPattern)),
+    None => Ok(unsafe {
+      core::slice::from_raw_parts(pod.as_ptr() as *const B, pod.len())
+    }),
+  }
+}
+
 /// Try to convert `&mut [A]` into `&mut [B]` (possibly with a change in
 /// length).
 ///
@@ -379,6 +786,7 @@ pub fn try_cast_slice_mut<
 ///
 /// This is [`try_from_bytes`] but will panic on error.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn from_bytes<T: CheckedBitPattern>(s: &[u8]) -> &T {
   match try_from_bytes(s) {
     Ok(t) => t,
@@ -392,6 +800,7 @@ pub fn from_bytes<T: CheckedBitPattern>(s: &[u8]) -> &T {
 ///
 /// This is [`try_from_bytes_mut`] but will panic on error.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn from_bytes_mut<T: NoUninit + CheckedBitPattern>(s: &mut [u8]) -> &mut T {
   match try_from_bytes_mut(s) {
     Ok(t) => t,
@@ -404,6 +813,7 @@ pub fn from_bytes_mut<T: NoUninit + CheckedBitPattern>(s: &mut [u8]) -> &mut T {
 /// ## Panics
 /// * This is like `try_pod_read_unaligned` but will panic on failure.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn pod_read_unaligned<T: AnyBitPattern>(bytes: &[u8]) -> T {
   match try_pod_read_unaligned(bytes) {
     Ok(t) => t,
@@ -417,6 +827,7 @@ pub fn pod_read_unaligned<T: AnyBitPattern>(bytes: &[u8]) -> T {
 ///
 /// * This is like [`try_cast`](try_cast), but will panic on a size mismatch.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast<A: NoUninit, B: CheckedBitPattern>(a: A) -> B {
   match try_cast(a) {
     Ok(t) => t,
@@ -424,12 +835,30 @@ pub fn cast<A: NoUninit, B: CheckedBitPattern>(a: A) -> B {
   }
 }
 
+/// Cast `[A; N]` into `[B; N]`, checking each element.
+///
+/// ## Panics
+///
+/// This is [`try_cast_array`] but will panic on error.
+#[cfg(feature = "min_const_generics")]
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn cast_array<A: NoUninit, B: CheckedBitPattern, const N: usize>(
+  a: [A; N],
+) -> [B; N] {
+  match try_cast_array(a) {
+    Ok(t) => t,
+    Err((_, e)) => something_went_wrong("cast_array", e),
+  }
+}
+
 /// Cast `&mut T` into `&mut U`.
 ///
 /// ## Panics
 ///
 /// This is [`try_cast_mut`] but will panic on error.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_mut<
   A: NoUninit + AnyBitPattern,
   B: NoUninit + CheckedBitPattern,
@@ -448,6 +877,7 @@ pub fn cast_mut<
 ///
 /// This is [`try_cast_ref`] but will panic on error.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_ref<A: NoUninit, B: CheckedBitPattern>(a: &A) -> &B {
   match try_cast_ref(a) {
     Ok(t) => t,
@@ -461,6 +891,7 @@ pub fn cast_ref<A: NoUninit, B: CheckedBitPattern>(a: &A) -> &B {
 ///
 /// This is [`try_cast_slice`] but will panic on error.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_slice<A: NoUninit, B: CheckedBitPattern>(a: &[A]) -> &[B] {
   match try_cast_slice(a) {
     Ok(t) => t,
@@ -474,6 +905,7 @@ pub fn cast_slice<A: NoUninit, B: CheckedBitPattern>(a: &[A]) -> &[B] {
 ///
 /// This is [`try_cast_slice_mut`] but will panic on error.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_slice_mut<
   A: NoUninit + AnyBitPattern,
   B: NoUninit + CheckedBitPattern,
@@ -166,6 +166,41 @@ pub unsafe trait Contiguous: Copy + 'static {
   }
 }
 
+/// The error for a failed `TryFrom` conversion from an integer into a
+/// [`Contiguous`] type, as generated by `#[derive(ContiguousTryFrom)]`.
+///
+/// This holds the value that didn't fit along with the inclusive range of
+/// values that would have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ContiguousOutOfRange<Int> {
+  /// The value that was converted.
+  pub value: Int,
+  /// The `MIN_VALUE` of the target type.
+  pub min: Int,
+  /// The `MAX_VALUE` of the target type.
+  pub max: Int,
+}
+
+impl<Int> ContiguousOutOfRange<Int> {
+  /// Makes the error for `value` not being a valid `C`.
+  #[inline]
+  pub fn new<C: Contiguous<Int = Int>>(value: Int) -> Self {
+    Self { value, min: C::MIN_VALUE, max: C::MAX_VALUE }
+  }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<Int: core::fmt::Display> core::fmt::Display for ContiguousOutOfRange<Int> {
+  fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+    write!(f, "{} is out of the range {}..={}", self.value, self.min, self.max)
+  }
+}
+#[cfg(feature = "extern_crate_std")]
+impl<Int: core::fmt::Debug + core::fmt::Display> std::error::Error
+  for ContiguousOutOfRange<Int>
+{
+}
+
 macro_rules! impl_contiguous {
   ($($src:ty as $repr:ident in [$min:expr, $max:expr];)*) => {$(
     unsafe impl Contiguous for $src {
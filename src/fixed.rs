@@ -0,0 +1,119 @@
+#![cfg(feature = "min_const_generics")]
+
+use super::*;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// A signed fixed-point number stored as a raw `i32`, with `FRAC` of those
+/// bits being the fractional part.
+///
+/// This is [`Pod`], so a byte buffer of raw fixed-point samples (eg: from a
+/// sensor or a DSP) can be cast straight to a slice of `Fixed` and then
+/// interpreted.
+///
+/// `FRAC` must be in the range `0..=31`. The inner value is public, and is
+/// simply the "raw" fixed-point bits.
+///
+/// ```rust
+/// # use bytemuck::{cast_slice, Fixed};
+/// let raw: [i32; 2] = [0x180, -0x40];
+/// let samples: &[Fixed<8>] = cast_slice(&raw);
+/// assert_eq!(samples[0].to_f32(), 1.5);
+/// assert_eq!(samples[1].to_f32(), -0.25);
+/// ```
+///
+/// Using any of the constructors or conversions with a `FRAC` above 31 is a
+/// compile error.
+///
+/// ```compile_fail
+/// # use bytemuck::Fixed;
+/// let x = Fixed::<32>::from_bits(1);
+/// ```
+///
+/// **Note:** like [`must_cast_array`](crate::must_cast_array), the check
+/// happens when the function is monomorphized, so a plain `cargo check` won't
+/// catch it, but `cargo build` will.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Fixed<const FRAC: u32>(pub i32);
+
+unsafe impl<const FRAC: u32> Zeroable for Fixed<FRAC> {}
+unsafe impl<const FRAC: u32> Pod for Fixed<FRAC> {}
+unsafe impl<const FRAC: u32> TransparentWrapper<i32> for Fixed<FRAC> {}
+
+impl<const FRAC: u32> Fixed<FRAC> {
+  /// The number of fractional bits.
+  pub const FRAC_BITS: u32 = FRAC;
+
+  // `assert!` in a const needs Rust 1.57, so we use an out of bounds index to
+  // make const evaluation fail when `FRAC` is too big.
+  const ASSERT_FRAC_VALID: () = [()][(FRAC > 31) as usize];
+
+  /// Makes a value from the raw fixed-point bits.
+  #[inline]
+  #[allow(clippy::let_unit_value)]
+  pub const fn from_bits(bits: i32) -> Self {
+    let _ = Self::ASSERT_FRAC_VALID;
+    Self(bits)
+  }
+
+  /// Gives the raw fixed-point bits.
+  #[inline]
+  #[allow(clippy::let_unit_value)]
+  pub const fn to_bits(self) -> i32 {
+    let _ = Self::ASSERT_FRAC_VALID;
+    self.0
+  }
+
+  /// Converts from `f32`.
+  ///
+  /// The fractional bits beyond what `FRAC` can hold are truncated (towards
+  /// zero), and values outside the representable range saturate. `NaN`
+  /// becomes zero.
+  #[inline]
+  pub fn from_f32(f: f32) -> Self {
+    Self((f * Self::scale()) as i32)
+  }
+
+  /// Converts to `f32`.
+  ///
+  /// This is exact as long as the raw value fits in the 24 bit mantissa of an
+  /// `f32`, otherwise it's rounded like any other `i32` to `f32` conversion.
+  #[inline]
+  pub fn to_f32(self) -> f32 {
+    self.0 as f32 / Self::scale()
+  }
+
+  #[inline]
+  #[allow(clippy::let_unit_value)]
+  fn scale() -> f32 {
+    let _ = Self::ASSERT_FRAC_VALID;
+    (1_u32 << FRAC) as f32
+  }
+}
+
+impl<const FRAC: u32> Add for Fixed<FRAC> {
+  type Output = Self;
+  #[inline]
+  fn add(self, rhs: Self) -> Self {
+    Self(self.0 + rhs.0)
+  }
+}
+impl<const FRAC: u32> AddAssign for Fixed<FRAC> {
+  #[inline]
+  fn add_assign(&mut self, rhs: Self) {
+    self.0 += rhs.0;
+  }
+}
+impl<const FRAC: u32> Sub for Fixed<FRAC> {
+  type Output = Self;
+  #[inline]
+  fn sub(self, rhs: Self) -> Self {
+    Self(self.0 - rhs.0)
+  }
+}
+impl<const FRAC: u32> SubAssign for Fixed<FRAC> {
+  #[inline]
+  fn sub_assign(&mut self, rhs: Self) {
+    self.0 -= rhs.0;
+  }
+}
@@ -1,6 +1,7 @@
 #![no_std]
 #![warn(missing_docs)]
 #![cfg_attr(feature = "nightly_portable_simd", feature(portable_simd))]
+#![cfg_attr(feature = "nightly_allocator_api", feature(allocator_api))]
 
 //! This crate gives small utilities for casting between plain data types.
 //!
@@ -19,7 +20,9 @@
 //! casts might fail (eg: `cast_ref::<[u8; 4], u32>` will fail if the reference
 //! isn't already aligned to 4). Each casting function has a "try" version which
 //! will return a `Result`, and the "normal" version which will simply panic on
-//! invalid input.
+//! invalid input. With the `track_caller` feature (Rust 1.46 or later) those
+//! panics report the location of your call instead of a location inside of
+//! this crate.
 //!
 //! ## Using Your Own Types
 //!
@@ -37,6 +40,21 @@
 //!   you can enable the `extern_crate_alloc` cargo feature for some additional
 //!   methods related to `Box` and `Vec`. Note that the `docs.rs` documentation
 //!   is always built with `extern_crate_alloc` cargo feature enabled.
+//! * Some features are off by default only because they need a Rust version
+//!   newer than this crate's minimum:
+//!   * `zeroable_maybe_uninit` (Rust 1.36) adds `Zeroable` for
+//!     `MaybeUninit<T>`.
+//!   * `maybe_uninit` (Rust 1.36) adds `read_unaligned_into_uninit` and
+//!     `try_read_unaligned_into_uninit`. It needs the same Rust version as
+//!     `zeroable_maybe_uninit`, but it's a separate feature because one adds
+//!     a trait impl and the other adds functions, and turning on either
+//!     shouldn't bring in the other.
+//!   * `track_caller` (Rust 1.46) makes panics report your call's location.
+//!   * `min_const_generics` (Rust 1.51) adds impls and functions for arrays of
+//!     any length.
+//!   * `const_fn_trait_bound` (Rust 1.61) makes some functions `const`.
+//!   * `wasm_simd` (Rust 1.54) and `aarch64_simd` (Rust 1.59) add impls for
+//!     the SIMD types of those targets.
 
 #[cfg(all(target_arch = "aarch64", feature = "aarch64_simd"))]
 use core::arch::aarch64;
@@ -105,15 +123,27 @@ mod contiguous;
 pub use contiguous::*;
 
 mod offset_of;
-pub use offset_of::*;
+
+mod new_unit;
+
+mod byte_swap;
+pub use byte_swap::*;
+
+mod pod_key;
+pub use pod_key::*;
 
 mod transparent;
 pub use transparent::*;
 
+#[cfg(feature = "min_const_generics")]
+mod fixed;
+#[cfg(feature = "min_const_generics")]
+pub use fixed::*;
+
 #[cfg(feature = "derive")]
 pub use bytemuck_derive::{
-  AnyBitPattern, CheckedBitPattern, Contiguous, NoUninit, Pod,
-  TransparentWrapper, Zeroable,
+  AnyBitPattern, ByteEq, CheckedBitPattern, Contiguous, ContiguousTryFrom,
+  NoUninit, Pod, TransparentWrapper, Zeroable,
 };
 
 /// The things that can go wrong when casting between [`Pod`] data forms.
@@ -135,13 +165,27 @@ pub enum PodCastError {
   ///
   /// This error is generated **only** by operations that cast allocated types
   /// (such as `Box` and `Vec`), because in that case the alignment must stay
-  /// exact.
+  /// exact, and by [`try_from_bytes_with_layout`] when the given layout is
+  /// less aligned than the target type.
   AlignmentMismatch,
 }
 #[cfg(not(target_arch = "spirv"))]
 impl core::fmt::Display for PodCastError {
   fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
-    write!(f, "{:?}", self)
+    f.write_str(match self {
+      PodCastError::TargetAlignmentGreaterAndInputNotAligned => {
+        "the input isn't aligned for the target type"
+      }
+      PodCastError::OutputSliceWouldHaveSlop => {
+        "the input isn't a whole number of elements of the target type"
+      }
+      PodCastError::SizeMismatch => {
+        "the input size doesn't match the size of the target type"
+      }
+      PodCastError::AlignmentMismatch => {
+        "the alignment of the input and target types must be the same"
+      }
+    })
   }
 }
 #[cfg(feature = "extern_crate_std")]
@@ -165,12 +209,37 @@ pub fn bytes_of_mut<T: NoUninit + AnyBitPattern>(t: &mut T) -> &mut [u8] {
   unsafe { internal::bytes_of_mut(t) }
 }
 
+/// Copies the bytes of `t` to the start of `out`, giving back how many bytes
+/// that was.
+///
+/// This is for filling in a caller provided buffer (eg: when serializing
+/// without allocating). The rest of `out` isn't touched.
+///
+/// ## Failure
+///
+/// * If `out` is shorter than the size of `T` this gives `SizeMismatch`, and
+///   nothing is written.
+#[inline]
+pub fn write_bytes_of<T: NoUninit>(
+  t: &T, out: &mut [u8],
+) -> Result<usize, PodCastError> {
+  let bytes = bytes_of(t);
+  match out.get_mut(..bytes.len()) {
+    Some(dest) => {
+      dest.copy_from_slice(bytes);
+      Ok(bytes.len())
+    }
+    None => Err(PodCastError::SizeMismatch),
+  }
+}
+
 /// Re-interprets `&[u8]` as `&T`.
 ///
 /// ## Panics
 ///
 /// This is [`try_from_bytes`] but will panic on error.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn from_bytes<T: AnyBitPattern>(s: &[u8]) -> &T {
   unsafe { internal::from_bytes(s) }
 }
@@ -181,10 +250,34 @@ pub fn from_bytes<T: AnyBitPattern>(s: &[u8]) -> &T {
 ///
 /// This is [`try_from_bytes_mut`] but will panic on error.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn from_bytes_mut<T: NoUninit + AnyBitPattern>(s: &mut [u8]) -> &mut T {
   unsafe { internal::from_bytes_mut(s) }
 }
 
+/// Re-interprets `&[u8]` as `&T` without checking the length or alignment.
+///
+/// With the `debug_checks` feature this checks the same things as
+/// [`from_bytes`] (and panics if they don't hold) no matter the build profile,
+/// which is for catching misuse during development. Without the feature there
+/// are no checks at all.
+///
+/// ## Safety
+///
+/// * The slice's length must be exactly the size of `T`.
+/// * The slice must be aligned for `T`.
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub unsafe fn from_bytes_unchecked<T: AnyBitPattern>(s: &[u8]) -> &T {
+  #[cfg(feature = "debug_checks")]
+  {
+    if let Err(e) = internal::try_from_bytes::<T>(s) {
+      internal::something_went_wrong("from_bytes_unchecked", e)
+    }
+  }
+  &*(s.as_ptr() as *const T)
+}
+
 /// Reads from the bytes as if they were a `T`.
 ///
 /// ## Failure
@@ -201,10 +294,61 @@ pub fn try_pod_read_unaligned<T: AnyBitPattern>(
 /// ## Panics
 /// * This is like `try_pod_read_unaligned` but will panic on failure.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn pod_read_unaligned<T: AnyBitPattern>(bytes: &[u8]) -> T {
   unsafe { internal::pod_read_unaligned(bytes) }
 }
 
+/// Reads from the bytes into an uninitialized `T` slot, giving back the now
+/// initialized value.
+///
+/// Like [`try_pod_read_unaligned`], the bytes don't need to be aligned. This
+/// is for when you already have the storage for a `T` (eg: in a preallocated
+/// buffer) and want to fill it in place. Once this succeeds, `out` is fully
+/// initialized, so `out.assume_init()` is sound.
+///
+/// The returned `&mut T` points into `out`, so you can use the value right
+/// away without any `unsafe` of your own.
+///
+/// ## Failure
+/// * If the `bytes` length is not equal to `size_of::<T>()`.
+#[cfg(feature = "maybe_uninit")]
+#[clippy::msrv = "1.36"]
+#[inline]
+pub fn try_read_unaligned_into_uninit<'a, T: AnyBitPattern>(
+  bytes: &[u8], out: &'a mut core::mem::MaybeUninit<T>,
+) -> Result<&'a mut T, PodCastError> {
+  if bytes.len() != size_of::<T>() {
+    return Err(PodCastError::SizeMismatch);
+  }
+  unsafe {
+    core::ptr::copy_nonoverlapping(
+      bytes.as_ptr(),
+      out.as_mut_ptr() as *mut u8,
+      size_of::<T>(),
+    );
+    Ok(&mut *out.as_mut_ptr())
+  }
+}
+
+/// Reads from the bytes into an uninitialized `T` slot.
+///
+/// ## Panics
+/// * This is like [`try_read_unaligned_into_uninit`] but will panic on
+///   failure.
+#[cfg(feature = "maybe_uninit")]
+#[clippy::msrv = "1.36"]
+#[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
+pub fn read_unaligned_into_uninit<'a, T: AnyBitPattern>(
+  bytes: &[u8], out: &'a mut core::mem::MaybeUninit<T>,
+) -> &'a mut T {
+  match try_read_unaligned_into_uninit(bytes, out) {
+    Ok(t) => t,
+    Err(e) => internal::something_went_wrong("read_unaligned_into_uninit", e),
+  }
+}
+
 /// Re-interprets `&[u8]` as `&T`.
 ///
 /// ## Failure
@@ -216,6 +360,187 @@ pub fn try_from_bytes<T: AnyBitPattern>(s: &[u8]) -> Result<&T, PodCastError> {
   unsafe { internal::try_from_bytes(s) }
 }
 
+/// Re-interprets the start of `&[u8]` as `&T`, also giving back the rest of
+/// the bytes.
+///
+/// This is a zero-copy way to step through a buffer of several values one at a
+/// time. Unlike [`try_pod_read_unaligned`] the bytes are borrowed rather than
+/// copied, so alignment *does* matter here.
+///
+/// ## Failure
+///
+/// * If the slice is shorter than the size of the new type
+/// * If the slice isn't aligned for the new type
+#[inline]
+pub fn from_bytes_prefix<T: AnyBitPattern>(
+  s: &[u8],
+) -> Result<(&T, &[u8]), PodCastError> {
+  if s.len() < size_of::<T>() {
+    Err(PodCastError::SizeMismatch)
+  } else {
+    let (head, tail) = s.split_at(size_of::<T>());
+    unsafe { internal::try_from_bytes(head) }.map(|t| (t, tail))
+  }
+}
+
+/// Iterates over `bytes` as a series of `T` values, read one at a time.
+///
+/// Each value is read with [`pod_read_unaligned`], so the bytes don't need to
+/// be aligned for `T`. Any bytes at the end that aren't enough for a whole `T`
+/// are skipped. A ZST can't be read from bytes, so for a ZST the iterator is
+/// always empty.
+///
+/// ```rust
+/// # use bytemuck::pod_iter;
+/// let bytes = [0_u8, 1, 0, 2, 0, 3, 0];
+/// let halves: Vec<u16> = pod_iter(&bytes[1..]).collect();
+/// assert_eq!(halves.len(), 3);
+/// assert_eq!(pod_iter::<u16>(&bytes).len(), 3);
+/// ```
+#[inline]
+pub fn pod_iter<T: AnyBitPattern>(bytes: &[u8]) -> PodIter<'_, T> {
+  let bytes = if size_of::<T>() == 0 { &bytes[..0] } else { bytes };
+  PodIter {
+    chunks: bytes.chunks_exact(size_of::<T>().max(1)),
+    _marker: PhantomData,
+  }
+}
+
+/// The iterator from [`pod_iter`].
+#[derive(Debug, Clone)]
+pub struct PodIter<'a, T> {
+  chunks: core::slice::ChunksExact<'a, u8>,
+  _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: AnyBitPattern> Iterator for PodIter<'a, T> {
+  type Item = T;
+
+  #[inline]
+  fn next(&mut self) -> Option<T> {
+    self.chunks.next().map(pod_read_unaligned)
+  }
+
+  #[inline]
+  fn size_hint(&self) -> (usize, Option<usize>) {
+    self.chunks.size_hint()
+  }
+
+  #[inline]
+  fn nth(&mut self, n: usize) -> Option<T> {
+    self.chunks.nth(n).map(pod_read_unaligned)
+  }
+}
+
+impl<'a, T: AnyBitPattern> DoubleEndedIterator for PodIter<'a, T> {
+  #[inline]
+  fn next_back(&mut self) -> Option<T> {
+    self.chunks.next_back().map(pod_read_unaligned)
+  }
+}
+
+impl<'a, T: AnyBitPattern> ExactSizeIterator for PodIter<'a, T> {}
+
+impl<'a, T: AnyBitPattern> core::iter::FusedIterator for PodIter<'a, T> {}
+
+/// Finds the first byte offset in `haystack` where the bytes of `needle`
+/// appear.
+///
+/// Every offset is checked, not just those aligned for `T`, so this can find a
+/// value at any position in a buffer (eg: a magic number in a damaged file).
+///
+/// This matches *bytes*, not values. For most types that's the same thing, but
+/// for floats it means that `0.0` won't match `-0.0`, and a `NaN` needle will
+/// match a `NaN` with the same bits.
+///
+/// A zero-sized `needle` is found at offset 0.
+#[inline]
+pub fn find_pod<T: NoUninit>(haystack: &[u8], needle: &T) -> Option<usize> {
+  let needle = bytes_of(needle);
+  if needle.is_empty() {
+    return Some(0);
+  }
+  haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+// Note: the function is only `const` with `const_fn_trait_bound`, so it's
+// written once here and then declared with or without the `const`.
+macro_rules! define_starts_with_pod {
+  ($($const:tt)?) => {
+    /// Checks if `bytes` begins with the bytes of `magic`.
+    ///
+    /// This is an exact byte comparison, meant for checking the magic number
+    /// at the start of a file format. If `bytes` is shorter than `T` this is
+    /// `false`.
+    ///
+    /// With the `const_fn_trait_bound` feature (Rust 1.61 or later) this is a
+    /// `const fn`, so the check can happen in a `const` context.
+    ///
+    /// ```rust
+    /// # use bytemuck::starts_with_pod;
+    /// let magic = u32::from_le_bytes(*b"RIFF");
+    /// assert!(starts_with_pod(b"RIFF\x24\x08\x00\x00WAVE", &magic));
+    /// assert!(!starts_with_pod(b"RIF", &magic));
+    /// ```
+    #[cfg_attr(feature = "const_fn_trait_bound", clippy::msrv = "1.61")]
+    #[inline]
+    pub $($const)? fn starts_with_pod<T: NoUninit>(
+      bytes: &[u8], magic: &T,
+    ) -> bool {
+      let size = size_of::<T>();
+      if bytes.len() < size {
+        return false;
+      }
+      let magic = magic as *const T as *const u8;
+      let mut i = 0;
+      while i < size {
+        // Safety: `T` is `NoUninit`, so all `size` bytes of `magic` are
+        // initialized.
+        if bytes[i] != unsafe { *magic.add(i) } {
+          return false;
+        }
+        i += 1;
+      }
+      true
+    }
+  };
+}
+#[cfg(feature = "const_fn_trait_bound")]
+define_starts_with_pod!(const);
+#[cfg(not(feature = "const_fn_trait_bound"))]
+define_starts_with_pod!();
+
+/// Re-interprets `&[u8]` as `&T`, checking the bytes against a given
+/// [`Layout`](core::alloc::Layout) rather than just the layout of `T`.
+///
+/// The layout can be over-aligned compared to `T`, which is useful when you
+/// want to be sure that a buffer keeps a stronger alignment than `T` itself
+/// needs (eg: a page aligned region of a memory mapped file).
+///
+/// ## Failure
+///
+/// * If `layout.size()` isn't the size of `T` this gives `SizeMismatch`
+/// * If `layout.align()` is less than the alignment of `T` this gives
+///   `AlignmentMismatch`
+/// * If the slice's length isn't exactly `layout.size()`
+/// * If the slice isn't aligned to `layout.align()`
+#[inline]
+pub fn try_from_bytes_with_layout<T: AnyBitPattern>(
+  s: &[u8], layout: core::alloc::Layout,
+) -> Result<&T, PodCastError> {
+  if layout.size() != size_of::<T>() {
+    Err(PodCastError::SizeMismatch)
+  } else if layout.align() < align_of::<T>() {
+    Err(PodCastError::AlignmentMismatch)
+  } else if s.len() != layout.size() {
+    Err(PodCastError::SizeMismatch)
+  } else if (s.as_ptr() as usize) % layout.align() != 0 {
+    Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+  } else {
+    unsafe { internal::try_from_bytes(s) }
+  }
+}
+
 /// Re-interprets `&mut [u8]` as `&mut T`.
 ///
 /// ## Failure
@@ -229,22 +554,170 @@ pub fn try_from_bytes_mut<T: NoUninit + AnyBitPattern>(
   unsafe { internal::try_from_bytes_mut(s) }
 }
 
+/// Re-interprets `&mut [u8]` as `&mut [T]`, with however many `T` the bytes
+/// hold.
+///
+/// This is [`try_cast_slice_mut`] with the input fixed to bytes, for when you
+/// want the call site to say that a buffer is being viewed as some number of
+/// `T` values. Use [`len_of_as`] to know ahead of time how many that will be.
+///
+/// ## Failure
+///
+/// * If the slice isn't aligned for the new type
+/// * If the slice's length isn't a whole multiple of the size of the new type
+/// * If `T` is a ZST and the slice isn't empty
+#[inline]
+pub fn from_bytes_slice_mut<T: NoUninit + AnyBitPattern>(
+  bytes: &mut [u8],
+) -> Result<&mut [T], PodCastError> {
+  try_cast_slice_mut(bytes)
+}
+
+/// Gives how many whole `T` values fit in `bytes`.
+///
+/// Any leftover bytes at the end are not counted. This doesn't look at
+/// alignment, only length.
+///
+/// A ZST can't be cast to or from bytes, so for a ZST this is always 0 (rather
+/// than dividing by zero).
+#[inline]
+pub fn len_of_as<T>(bytes: &[u8]) -> usize {
+  match size_of::<T>() {
+    0 => 0,
+    size => bytes.len() / size,
+  }
+}
+
 /// Cast `T` into `U`
 ///
 /// ## Panics
 ///
 /// * This is like [`try_cast`](try_cast), but will panic on a size mismatch.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast<A: NoUninit, B: AnyBitPattern>(a: A) -> B {
   unsafe { internal::cast(a) }
 }
 
+/// Cast `[A; N]` into `[B; M]`, with the sizes checked at compile time.
+///
+/// The total size of the input and output arrays must be the same, and if it
+/// isn't then this is a compile error rather than a runtime failure, so there's
+/// no checking left to do at runtime.
+///
+/// ```rust
+/// # use bytemuck::must_cast_array;
+/// let halves: [u16; 4] = [1, 2, 3, 4];
+/// let bytes: [u8; 8] = must_cast_array(halves);
+/// let back: [u16; 4] = must_cast_array(bytes);
+/// assert_eq!(back, halves);
+/// ```
+///
+/// ```compile_fail
+/// # use bytemuck::must_cast_array;
+/// let bytes: [u8; 7] = must_cast_array([0_u16; 4]);
+/// ```
+///
+/// **Note:** the size check happens when the function is monomorphized, so a
+/// plain `cargo check` won't catch a mismatch, but `cargo build` will.
+#[cfg(feature = "min_const_generics")]
+#[inline]
+pub fn must_cast_array<
+  A: NoUninit,
+  B: AnyBitPattern,
+  const N: usize,
+  const M: usize,
+>(
+  a: [A; N],
+) -> [B; M] {
+  #[allow(clippy::let_unit_value)]
+  let _ = ArraySizeCheck::<A, B, N, M>::ASSERT_SIZE_EQUAL;
+  unsafe { transmute!(a) }
+}
+
+#[cfg(feature = "min_const_generics")]
+struct ArraySizeCheck<A, B, const N: usize, const M: usize>(
+  PhantomData<([A; N], [B; M])>,
+);
+#[cfg(feature = "min_const_generics")]
+impl<A, B, const N: usize, const M: usize> ArraySizeCheck<A, B, N, M> {
+  // `assert!` in a const needs Rust 1.57, so we use an out of bounds index to
+  // make const evaluation fail when the sizes don't match.
+  const ASSERT_SIZE_EQUAL: () =
+    [()][(N * size_of::<A>() != M * size_of::<B>()) as usize];
+}
+
+/// Views a slice as a slice of `N` element chunks.
+///
+/// This is like the standard library's `as_chunks`, except that there can't be
+/// any elements left over.
+///
+/// ```rust
+/// # use bytemuck::as_chunks_pod;
+/// let tiles: &[[u8; 4]] = as_chunks_pod(&[1_u8, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+/// assert_eq!(tiles, &[[1, 2, 3, 4], [5, 6, 7, 8]]);
+/// ```
+///
+/// ## Failure
+///
+/// * If `N` is 0 this gives `SizeMismatch`
+/// * If the slice's length isn't a multiple of `N` this gives
+///   `OutputSliceWouldHaveSlop`
+#[cfg(feature = "min_const_generics")]
+#[inline]
+pub fn as_chunks_pod<T: Pod, const N: usize>(
+  slice: &[T],
+) -> Result<&[[T; N]], PodCastError> {
+  if N == 0 {
+    Err(PodCastError::SizeMismatch)
+  } else if slice.len() % N != 0 {
+    Err(PodCastError::OutputSliceWouldHaveSlop)
+  } else {
+    // Safety: `[T; N]` has the same alignment as `T`, and `len / N` chunks
+    // covers exactly the same memory as the input.
+    Ok(unsafe {
+      core::slice::from_raw_parts(
+        slice.as_ptr() as *const [T; N],
+        slice.len() / N,
+      )
+    })
+  }
+}
+
+/// As [`as_chunks_pod`], but for mutable slices.
+///
+/// ## Failure
+///
+/// * If `N` is 0 this gives `SizeMismatch`
+/// * If the slice's length isn't a multiple of `N` this gives
+///   `OutputSliceWouldHaveSlop`
+#[cfg(feature = "min_const_generics")]
+#[inline]
+pub fn as_chunks_pod_mut<T: Pod, const N: usize>(
+  slice: &mut [T],
+) -> Result<&mut [[T; N]], PodCastError> {
+  if N == 0 {
+    Err(PodCastError::SizeMismatch)
+  } else if slice.len() % N != 0 {
+    Err(PodCastError::OutputSliceWouldHaveSlop)
+  } else {
+    // Safety: as with `as_chunks_pod`.
+    Ok(unsafe {
+      core::slice::from_raw_parts_mut(
+        slice.as_mut_ptr() as *mut [T; N],
+        slice.len() / N,
+      )
+    })
+  }
+}
+
 /// Cast `&mut T` into `&mut U`.
 ///
 /// ## Panics
 ///
 /// This is [`try_cast_mut`] but will panic on error.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_mut<A: NoUninit + AnyBitPattern, B: NoUninit + AnyBitPattern>(
   a: &mut A,
 ) -> &mut B {
@@ -257,6 +730,7 @@ pub fn cast_mut<A: NoUninit + AnyBitPattern, B: NoUninit + AnyBitPattern>(
 ///
 /// This is [`try_cast_ref`] but will panic on error.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_ref<A: NoUninit, B: AnyBitPattern>(a: &A) -> &B {
   unsafe { internal::cast_ref(a) }
 }
@@ -267,6 +741,7 @@ pub fn cast_ref<A: NoUninit, B: AnyBitPattern>(a: &A) -> &B {
 ///
 /// This is [`try_cast_slice`] but will panic on error.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_slice<A: NoUninit, B: AnyBitPattern>(a: &[A]) -> &[B] {
   unsafe { internal::cast_slice(a) }
 }
@@ -277,6 +752,7 @@ pub fn cast_slice<A: NoUninit, B: AnyBitPattern>(a: &[A]) -> &[B] {
 ///
 /// This is [`try_cast_slice_mut`] but will panic on error.
 #[inline]
+#[cfg_attr(feature = "track_caller", track_caller)]
 pub fn cast_slice_mut<
   A: NoUninit + AnyBitPattern,
   B: NoUninit + AnyBitPattern,
@@ -294,6 +770,20 @@ pub fn pod_align_to<T: NoUninit, U: AnyBitPattern>(
   unsafe { vals.align_to::<U>() }
 }
 
+/// As [`pod_align_to`], but also gives the byte offset of the middle slice.
+///
+/// The offset is where the aligned `U` values begin, measured in bytes from the
+/// start of `vals`, which is handy for keeping an external cursor in sync. It's
+/// always `prefix.len() * size_of::<T>()`. When no `U` fits in `vals` the
+/// middle is empty and the offset is the byte length of the whole slice.
+#[inline]
+pub fn pod_align_to_offset<T: NoUninit, U: AnyBitPattern>(
+  vals: &[T],
+) -> (usize, &[T], &[U], &[T]) {
+  let (prefix, middle, suffix) = pod_align_to::<T, U>(vals);
+  (size_of_val(prefix), prefix, middle, suffix)
+}
+
 /// As `align_to_mut`, but safe because of the [`Pod`] bound.
 #[inline]
 pub fn pod_align_to_mut<
@@ -328,6 +818,15 @@ pub fn try_cast<A: NoUninit, B: AnyBitPattern>(
 ///
 /// * If the reference isn't aligned in the new type
 /// * If the source type and target type aren't the same size.
+///
+/// The alignment is checked first, so you can tell the two cases apart by the
+/// error variant:
+///
+/// * [`PodCastError::TargetAlignmentGreaterAndInputNotAligned`] means that `U`
+///   needs a higher alignment than `T` and this particular reference doesn't
+///   have it. This is returned even if the sizes are also different.
+/// * [`PodCastError::SizeMismatch`] means that the reference was suitably
+///   aligned (or `U` has no greater alignment than `T`), but the sizes differ.
 #[inline]
 pub fn try_cast_ref<A: NoUninit, B: AnyBitPattern>(
   a: &A,
@@ -337,7 +836,7 @@ pub fn try_cast_ref<A: NoUninit, B: AnyBitPattern>(
 
 /// Try to convert a `&mut T` into `&mut U`.
 ///
-/// As [`try_cast_ref`], but `mut`.
+/// As [`try_cast_ref`], but `mut`. The error variants have the same meaning.
 #[inline]
 pub fn try_cast_mut<
   A: NoUninit + AnyBitPattern,
@@ -0,0 +1,69 @@
+/// Declares a `#[repr(transparent)]` newtype over a [`Pod`](crate::Pod) type,
+/// such as a unit of measure.
+///
+/// The new type gets [`Zeroable`](crate::Zeroable), [`Pod`](crate::Pod), and
+/// [`TransparentWrapper`](crate::TransparentWrapper) impls, along with `Clone`,
+/// `Copy`, and `new`/`get` methods. This means that slices of the inner type
+/// and slices of the new type can be cast back and forth.
+///
+/// You can put attributes (eg: doc comments or more derives) and a visibility
+/// before the name of the type, and attributes before the inner type to put
+/// them on the field. The macro doesn't write any docs for the type itself, so
+/// give it a doc comment if your crate uses `#![deny(missing_docs)]`. The inner
+/// type must be `Pod`, or there's a compile error.
+///
+/// ```rust
+/// # use bytemuck::{cast_slice, new_unit, TransparentWrapper};
+/// new_unit!(
+///   /// A length in meters.
+///   #[derive(Debug, PartialEq)]
+///   pub Meters,
+///   /// The number of meters.
+///   f32
+/// );
+///
+/// let raw = [1.0_f32, 2.5, 4.0];
+/// let lengths: &[Meters] = Meters::wrap_slice(&raw);
+/// assert_eq!(lengths[1], Meters::new(2.5));
+///
+/// let back: &[f32] = cast_slice(lengths);
+/// assert_eq!(back, &raw[..]);
+/// ```
+///
+/// ```compile_fail
+/// # use bytemuck::new_unit;
+/// // `char` isn't `Pod`
+/// new_unit!(Letter, char);
+/// ```
+#[macro_export]
+macro_rules! new_unit {
+  (
+    $(#[$attr:meta])* $vis:vis $name:ident,
+    $(#[$field_attr:meta])* $inner:ty $(,)?
+  ) => {
+    $(#[$attr])*
+    #[derive(Clone, Copy)]
+    #[repr(transparent)]
+    $vis struct $name($(#[$field_attr])* pub $inner);
+
+    impl $name {
+      /// Wraps a value.
+      #[inline]
+      pub const fn new(value: $inner) -> Self {
+        Self(value)
+      }
+
+      /// Gives the wrapped value.
+      #[inline]
+      pub const fn get(self) -> $inner {
+        self.0
+      }
+    }
+
+    // Note: the `where` bounds make it a compile error to use an inner type
+    // that isn't `Pod`, rather than making an unsound impl.
+    unsafe impl $crate::Zeroable for $name where $inner: $crate::Pod {}
+    unsafe impl $crate::Pod for $name where $inner: $crate::Pod {}
+    unsafe impl $crate::TransparentWrapper<$inner> for $name {}
+  };
+}
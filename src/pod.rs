@@ -314,6 +314,11 @@ unsafe impl Pod for x86_64::__m256 {}
 #[cfg(target_arch = "x86_64")]
 unsafe impl Pod for x86_64::__m256d {}
 
+#[cfg(feature = "half")]
+unsafe impl Pod for half::f16 {}
+#[cfg(feature = "half")]
+unsafe impl Pod for half::bf16 {}
+
 #[cfg(feature = "nightly_portable_simd")]
 unsafe impl<T, const N: usize> Pod for core::simd::Simd<T, N>
 where
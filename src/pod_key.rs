@@ -0,0 +1,78 @@
+use super::*;
+use core::{
+  borrow::Borrow,
+  hash::{Hash, Hasher},
+  ops::{Deref, DerefMut},
+};
+
+/// A wrapper that compares and hashes a value by its bytes.
+///
+/// This lets any [`NoUninit`] type be used as a `HashMap` or `HashSet` key,
+/// even if it doesn't implement `Eq` and `Hash` itself (eg: a type from
+/// another crate). Since a `NoUninit` type has no padding, two values are equal
+/// exactly when all their bytes are.
+///
+/// This means floats are compared by their bits: `0.0` and `-0.0` are
+/// different keys, and a `NaN` is equal to a `NaN` with the same bits.
+///
+/// ```rust
+/// # use bytemuck::PodKey;
+/// # use std::collections::HashMap;
+/// let mut names = HashMap::new();
+/// names.insert(PodKey([1.0_f32, 2.0]), "a");
+/// assert_eq!(names.get(&PodKey([1.0_f32, 2.0])), Some(&"a"));
+/// ```
+///
+/// A `PodKey<T>` borrows as `[u8]`, with the same `Eq` and `Hash` as the
+/// bytes of the value, so a map can also be searched by bytes:
+///
+/// ```rust
+/// # use bytemuck::{bytes_of, PodKey};
+/// # use std::collections::HashMap;
+/// let mut names = HashMap::new();
+/// names.insert(PodKey(7_u32), "seven");
+/// assert_eq!(names.get(bytes_of(&7_u32)), Some(&"seven"));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct PodKey<T>(pub T);
+
+unsafe impl<T> TransparentWrapper<T> for PodKey<T> {}
+
+impl<T: NoUninit> PartialEq for PodKey<T> {
+  #[inline]
+  fn eq(&self, other: &Self) -> bool {
+    bytes_of(&self.0) == bytes_of(&other.0)
+  }
+}
+
+impl<T: NoUninit> Eq for PodKey<T> {}
+
+impl<T: NoUninit> Hash for PodKey<T> {
+  #[inline]
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    bytes_of(&self.0).hash(state)
+  }
+}
+
+impl<T> Deref for PodKey<T> {
+  type Target = T;
+  #[inline]
+  fn deref(&self) -> &T {
+    &self.0
+  }
+}
+
+impl<T> DerefMut for PodKey<T> {
+  #[inline]
+  fn deref_mut(&mut self) -> &mut T {
+    &mut self.0
+  }
+}
+
+impl<T: NoUninit> Borrow<[u8]> for PodKey<T> {
+  #[inline]
+  fn borrow(&self) -> &[u8] {
+    bytes_of(&self.0)
+  }
+}
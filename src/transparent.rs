@@ -247,3 +247,52 @@ pub unsafe trait TransparentWrapper<Inner: ?Sized> {
 }
 
 unsafe impl<T> TransparentWrapper<T> for core::num::Wrapping<T> {}
+
+/// Implements `AsRef`, `AsMut`, and `Borrow` of the inner type for a
+/// [`TransparentWrapper`].
+///
+/// The impls are [`peel_ref`](TransparentWrapper::peel_ref) and
+/// [`peel_mut`](TransparentWrapper::peel_mut), so they cost nothing. The
+/// wrapper must already implement `TransparentWrapper<Inner>`.
+///
+/// `Borrow` expects that `Eq`, `Ord`, and `Hash` give the same results for
+/// the wrapper as for the inner type, so only use this when that's true (eg:
+/// when those traits are derived on the wrapper).
+///
+/// ```rust
+/// # use bytemuck::{impl_transparent_as_ref, TransparentWrapper};
+/// #[repr(transparent)]
+/// struct Name(String);
+/// unsafe impl TransparentWrapper<String> for Name {}
+/// impl_transparent_as_ref!(Name, String);
+///
+/// let mut name = Name("Ferris".to_string());
+/// name.as_mut().push_str(" the Crab");
+/// let inner: &String = name.as_ref();
+/// assert_eq!(inner, "Ferris the Crab");
+/// ```
+#[macro_export]
+macro_rules! impl_transparent_as_ref {
+  ($wrapper:ty, $inner:ty $(,)?) => {
+    impl ::core::convert::AsRef<$inner> for $wrapper {
+      #[inline]
+      fn as_ref(&self) -> &$inner {
+        <$wrapper as $crate::TransparentWrapper<$inner>>::peel_ref(self)
+      }
+    }
+
+    impl ::core::convert::AsMut<$inner> for $wrapper {
+      #[inline]
+      fn as_mut(&mut self) -> &mut $inner {
+        <$wrapper as $crate::TransparentWrapper<$inner>>::peel_mut(self)
+      }
+    }
+
+    impl ::core::borrow::Borrow<$inner> for $wrapper {
+      #[inline]
+      fn borrow(&self) -> &$inner {
+        <$wrapper as $crate::TransparentWrapper<$inner>>::peel_ref(self)
+      }
+    }
+  };
+}
@@ -99,6 +99,34 @@ unsafe impl<
 #[cfg(feature = "min_const_generics")]
 unsafe impl<T, const N: usize> Zeroable for [T; N] where T: Zeroable {}
 
+// Note: with `zeroable_maybe_uninit` the blanket `MaybeUninit<T>` impl above
+// already covers this.
+#[cfg(all(
+  feature = "min_const_generics",
+  not(feature = "zeroable_maybe_uninit")
+))]
+#[clippy::msrv = "1.51"]
+unsafe impl<T, const N: usize> Zeroable for core::mem::MaybeUninit<[T; N]> {}
+
+/// Makes an all-zeroes `[T; N]`.
+///
+/// This zeroes a `MaybeUninit<[T; N]>` in place and then assumes it's
+/// initialized, which is sound because `T: Zeroable`. You get the same value as
+/// `<[T; N]>::zeroed()`, but it doesn't need you to name the array type.
+///
+/// ```rust
+/// # use bytemuck::init_zeroed_array;
+/// let table: [u64; 100] = init_zeroed_array();
+/// assert!(table.iter().all(|&x| x == 0));
+/// ```
+#[cfg(feature = "min_const_generics")]
+#[clippy::msrv = "1.51"]
+#[inline]
+pub fn init_zeroed_array<T: Zeroable, const N: usize>() -> [T; N] {
+  let zeroed = core::mem::MaybeUninit::<[T; N]>::zeroed();
+  unsafe { zeroed.assume_init() }
+}
+
 #[cfg(not(feature = "min_const_generics"))]
 impl_unsafe_marker_for_array!(
   Zeroable, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18,
@@ -344,6 +372,12 @@ unsafe impl Zeroable for x86_64::__m256 {}
 #[cfg(target_arch = "x86_64")]
 unsafe impl Zeroable for x86_64::__m256d {}
 
+// Both are `repr(transparent)` over `u16`, and all zeroes is `+0.0`.
+#[cfg(feature = "half")]
+unsafe impl Zeroable for half::f16 {}
+#[cfg(feature = "half")]
+unsafe impl Zeroable for half::bf16 {}
+
 #[cfg(feature = "nightly_portable_simd")]
 unsafe impl<T, const N: usize> Zeroable for core::simd::Simd<T, N>
 where
@@ -25,4 +25,17 @@ unsafe impl ZeroableInOption for NonZeroU64 {}
 unsafe impl ZeroableInOption for NonZeroU128 {}
 unsafe impl ZeroableInOption for NonZeroUsize {}
 
+// Note: unlike `PodInOption`, this doesn't need `unsound_ptr_pod_impl`. A
+// null `Option<NonNull<T>>` is just `None`, which is always a fine value to
+// have, whereas being `Pod` would also allow making a `Some` from any address.
 unsafe impl<T> ZeroableInOption for NonNull<T> {}
+
+// `Box<T>` (for sized `T`) is guaranteed to use null as the `None` niche, the
+// same as `NonNull<T>`.
+//
+// Note: there's intentionally no impl for `Vec<T>`. The layout of `Vec` isn't
+// specified, and which field holds the niche has changed between compiler
+// versions (it's currently the capacity, which can't exceed `isize::MAX`), so
+// an all zeroes `Option<Vec<T>>` isn't guaranteed to be `None`.
+#[cfg(feature = "extern_crate_alloc")]
+unsafe impl<T> ZeroableInOption for alloc::boxed::Box<T> {}
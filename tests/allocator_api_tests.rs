@@ -0,0 +1,75 @@
+#![cfg(feature = "nightly_allocator_api")]
+#![feature(allocator_api)]
+
+use bytemuck::*;
+use core::{
+  alloc::{AllocError, Allocator, Layout},
+  cell::Cell,
+  ptr::NonNull,
+};
+use std::alloc::Global;
+
+/// Forwards to the global allocator, but keeps count of what it's asked for.
+#[derive(Default)]
+struct CountingAlloc {
+  zeroed_allocs: Cell<usize>,
+  live_bytes: Cell<usize>,
+}
+
+unsafe impl Allocator for &CountingAlloc {
+  fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+    self.live_bytes.set(self.live_bytes.get() + layout.size());
+    Global.allocate(layout)
+  }
+
+  fn allocate_zeroed(
+    &self, layout: Layout,
+  ) -> Result<NonNull<[u8]>, AllocError> {
+    self.zeroed_allocs.set(self.zeroed_allocs.get() + 1);
+    self.live_bytes.set(self.live_bytes.get() + layout.size());
+    Global.allocate_zeroed(layout)
+  }
+
+  unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+    self.live_bytes.set(self.live_bytes.get() - layout.size());
+    Global.deallocate(ptr, layout)
+  }
+}
+
+#[test]
+fn test_zeroed_box_in() {
+  let alloc = CountingAlloc::default();
+  {
+    let b: Box<[u64; 512], _> = zeroed_box_in(&alloc);
+    assert!(b.iter().all(|&x| x == 0));
+    assert_eq!(alloc.zeroed_allocs.get(), 1);
+    assert_eq!(alloc.live_bytes.get(), 4096);
+  }
+  assert_eq!(alloc.live_bytes.get(), 0);
+
+  let _: Box<(), _> = try_zeroed_box_in(&alloc).unwrap();
+  assert_eq!(alloc.zeroed_allocs.get(), 1);
+  assert_eq!(alloc.live_bytes.get(), 0);
+}
+
+#[test]
+fn test_zeroed_vec_in() {
+  let alloc = CountingAlloc::default();
+  {
+    let v: Vec<u32, _> = zeroed_vec_in(100, &alloc);
+    assert_eq!(v.len(), 100);
+    assert_eq!(v.capacity(), 100);
+    assert!(v.iter().all(|&x| x == 0));
+    assert_eq!(alloc.zeroed_allocs.get(), 1);
+    assert_eq!(alloc.live_bytes.get(), 400);
+  }
+  assert_eq!(alloc.live_bytes.get(), 0);
+
+  let empty: Vec<u32, _> = zeroed_vec_in(0, &alloc);
+  assert!(empty.is_empty());
+  let zsts: Vec<(), _> = zeroed_vec_in(10, &alloc);
+  assert_eq!(zsts.len(), 10);
+  assert_eq!(alloc.zeroed_allocs.get(), 1);
+
+  assert!(try_zeroed_vec_in::<u32, _>(usize::MAX, &alloc).is_err());
+}
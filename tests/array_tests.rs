@@ -10,3 +10,75 @@ pub fn test_cast_long_array() {
   let x = [0u32; 65];
   let _: [u16; 130] = bytemuck::cast(x);
 }
+
+#[cfg(feature = "min_const_generics")]
+#[test]
+pub fn test_must_cast_array() {
+  let x: [u16; 4] = [0x0102, 0x0304, 0x0506, 0x0708];
+  let bytes: [u8; 8] = bytemuck::must_cast_array(x);
+  let mut expected = [0_u8; 8];
+  for (chunk, v) in expected.chunks_mut(2).zip(x.iter()) {
+    chunk.copy_from_slice(&v.to_ne_bytes());
+  }
+  assert_eq!(bytes, expected);
+
+  let back: [u16; 4] = bytemuck::must_cast_array(bytes);
+  assert_eq!(back, x);
+  let wide: [u64; 1] = bytemuck::must_cast_array(x);
+  assert_eq!(wide[0].to_ne_bytes(), expected);
+}
+
+#[cfg(feature = "min_const_generics")]
+#[test]
+pub fn test_init_zeroed_array() {
+  use bytemuck::Zeroable;
+  use core::mem::MaybeUninit;
+
+  let table: [u64; 100] = bytemuck::init_zeroed_array();
+  assert_eq!(table.len(), 100);
+  assert!(table.iter().all(|&x| x == 0));
+
+  let bools: [bool; 3] = bytemuck::init_zeroed_array();
+  assert_eq!(bools, [false; 3]);
+
+  let uninit = MaybeUninit::<[u64; 100]>::zeroed();
+  let table = unsafe { uninit.assume_init() };
+  assert!(table.iter().all(|&x| x == 0));
+  let _: MaybeUninit<[u64; 100]> = Zeroable::zeroed();
+}
+
+#[cfg(feature = "min_const_generics")]
+#[test]
+pub fn test_byte_swap_array() {
+  use bytemuck::ByteSwap;
+
+  let mut rows: [[u16; 2]; 2] = [[0x0102, 0x0304], [0x0506, 0x0708]];
+  bytemuck::swap_endianness_slice(&mut rows);
+  assert_eq!(rows, [[0x0201, 0x0403], [0x0605, 0x0807]]);
+  let mut pair = [0x0102_u16, 0x0304];
+  pair.byte_swap_in_place();
+  assert_eq!(pair, [0x0201, 0x0403]);
+}
+
+#[cfg(feature = "min_const_generics")]
+#[test]
+pub fn test_as_chunks_pod() {
+  use bytemuck::{as_chunks_pod, as_chunks_pod_mut, PodCastError};
+
+  let mut bytes: [u8; 12] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+  let tiles: &[[u8; 4]] = as_chunks_pod(&bytes).unwrap();
+  assert_eq!(tiles, &[[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11]]);
+
+  assert_eq!(
+    as_chunks_pod::<u8, 4>(&bytes[..10]),
+    Err(PodCastError::OutputSliceWouldHaveSlop)
+  );
+  assert_eq!(as_chunks_pod::<u8, 0>(&bytes), Err(PodCastError::SizeMismatch));
+  assert_eq!(as_chunks_pod::<u8, 4>(&[]), Ok(&[][..]));
+
+  let tiles: &mut [[u8; 3]] = as_chunks_pod_mut(&mut bytes).unwrap();
+  assert_eq!(tiles.len(), 4);
+  tiles[1] = [0xFF; 3];
+  assert_eq!(bytes[3..6], [0xFF; 3]);
+  assert!(as_chunks_pod_mut::<u8, 5>(&mut bytes).is_err());
+}
@@ -1,3 +1,11 @@
+// Note: these older tests spell out some casts and sizes that newer clippy
+// versions consider redundant.
+#![allow(
+  clippy::unnecessary_cast,
+  clippy::manual_slice_size_calculation,
+  clippy::unnecessary_mut_passed
+)]
+
 use core::mem::size_of;
 
 use bytemuck::*;
@@ -91,6 +99,32 @@ fn test_types() {
   let _: Result<&mut [i32], PodCastError> = try_cast_slice_mut(&mut [1.0_f32]);
 }
 
+#[test]
+fn test_try_cast_ref_error_variants() {
+  let u32s = [0_u32; 2];
+  let bytes: &[u8] = cast_slice(&u32s);
+
+  // u32 needs more alignment than this u8 has.
+  assert_eq!(
+    try_cast_ref::<u8, u32>(&bytes[1]),
+    Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+  );
+  // aligned, but still not the right size.
+  assert_eq!(
+    try_cast_ref::<u8, u32>(&bytes[4]),
+    Err(PodCastError::SizeMismatch)
+  );
+  // same alignment, different size.
+  assert_eq!(
+    try_cast_ref::<[u16; 2], u16>(&[1, 2]),
+    Err(PodCastError::SizeMismatch)
+  );
+  assert_eq!(
+    try_cast_mut::<[u16; 2], u16>(&mut [1, 2]),
+    Err(PodCastError::SizeMismatch)
+  );
+}
+
 #[test]
 fn test_bytes_of() {
   assert_eq!(bytes_of(&0xaabbccdd_u32), &0xaabbccdd_u32.to_ne_bytes());
@@ -145,6 +179,56 @@ fn test_try_from_bytes_mut() {
   );
 }
 
+#[test]
+fn test_from_bytes_prefix() {
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  #[repr(C)]
+  struct Header {
+    tag: u32,
+    len: u32,
+  }
+  unsafe impl Zeroable for Header {}
+  unsafe impl Pod for Header {}
+
+  let u32s = [1_u32, 2, 3, 4, 0xaabbccdd];
+  let bytes = bytemuck::cast_slice::<u32, u8>(&u32s);
+
+  let (first, rest) = from_bytes_prefix::<Header>(bytes).unwrap();
+  assert_eq!(first, &Header { tag: 1, len: 2 });
+  assert!(core::ptr::eq(first as *const Header as *const u32, &u32s[0]));
+  let (second, rest) = from_bytes_prefix::<Header>(rest).unwrap();
+  assert_eq!(second, &Header { tag: 3, len: 4 });
+  assert!(core::ptr::eq(second as *const Header as *const u32, &u32s[2]));
+  assert_eq!(rest, &0xaabbccdd_u32.to_ne_bytes());
+
+  assert_eq!(
+    from_bytes_prefix::<Header>(rest),
+    Err(PodCastError::SizeMismatch)
+  );
+  assert_eq!(
+    from_bytes_prefix::<u32>(&bytes[1..]),
+    Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+  );
+  assert_eq!(from_bytes_prefix::<u32>(&bytes[16..]), Ok((&u32s[4], &[][..])));
+}
+
+#[test]
+fn test_checked_try_pod_read_unaligned() {
+  assert_eq!(checked::try_pod_read_unaligned::<bool>(&[1]), Ok(true));
+  assert_eq!(
+    checked::try_pod_read_unaligned::<bool>(&[2]),
+    Err(checked::CheckedCastError::InvalidBitPattern)
+  );
+  let x = 'x' as u32;
+  assert_eq!(checked::try_pod_read_unaligned::<char>(bytes_of(&x)), Ok('x'));
+  // the bytes are read as the value, not as a pointer to it
+  let bytes = [0xFF_u8; 4];
+  assert_eq!(
+    checked::try_pod_read_unaligned::<char>(&bytes),
+    Err(checked::CheckedCastError::InvalidBitPattern)
+  );
+}
+
 #[test]
 fn test_from_bytes() {
   let abcd = 0xaabbccdd_u32;
@@ -192,3 +276,399 @@ fn test_panics() {
   let aligned_bytes = bytemuck::cast_slice::<u32, u8>(&[0, 0]);
   should_panic!(from_bytes::<u32>(&aligned_bytes[1..5]));
 }
+
+#[test]
+fn test_pod_align_to_offset() {
+  let words: [u32; 4] = [1, 2, 3, 4];
+  let bytes: &[u8] = cast_slice(&words);
+
+  // already aligned, so the middle starts right away
+  let (offset, prefix, middle, suffix) =
+    bytemuck::pod_align_to_offset::<u8, u32>(bytes);
+  assert_eq!(offset, 0);
+  assert!(prefix.is_empty());
+  assert_eq!(middle, &words[..]);
+  assert!(suffix.is_empty());
+
+  // one byte in, the middle starts at the next 4 byte boundary
+  let (offset, prefix, middle, suffix) =
+    bytemuck::pod_align_to_offset::<u8, u32>(&bytes[1..]);
+  assert_eq!(offset, prefix.len());
+  assert_eq!(offset, 3);
+  assert_eq!(middle, &words[1..]);
+  assert_eq!(suffix.len(), 0);
+  assert_eq!(offset + size_of_val(middle) + suffix.len(), bytes[1..].len());
+
+  // offset is in bytes even when the input isn't bytes
+  let halves: &[u16] = cast_slice(&words);
+  let (offset, prefix, middle, _) =
+    bytemuck::pod_align_to_offset::<u16, u32>(&halves[1..]);
+  assert_eq!(offset, size_of_val(prefix));
+  assert_eq!(offset, 2);
+  assert_eq!(middle, &words[1..]);
+
+  // nothing fits, so everything is prefix
+  let (offset, prefix, middle, suffix) =
+    bytemuck::pod_align_to_offset::<u8, u32>(&bytes[1..3]);
+  assert_eq!(offset, 2);
+  assert_eq!(prefix.len(), 2);
+  assert!(middle.is_empty());
+  assert!(suffix.is_empty());
+}
+
+#[test]
+fn test_from_bytes_slice_mut() {
+  let mut words = [0_u32; 3];
+  let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut words);
+  let len = bytemuck::len_of_as::<u16>(bytes);
+  assert_eq!(len, 6);
+
+  let halves: &mut [u16] = bytemuck::from_bytes_slice_mut(bytes).unwrap();
+  assert_eq!(halves.len(), len);
+  halves[0] = 0xFFFF;
+  halves[5] = 0x1234;
+  assert_eq!(bytes[0..2], [0xFF, 0xFF]);
+  assert_eq!(bytes[10..12], 0x1234_u16.to_ne_bytes());
+
+  // the trailing odd byte isn't counted, and can't be cast
+  let bytes = &mut bytes[..11];
+  assert_eq!(bytemuck::len_of_as::<u16>(bytes), 5);
+  assert_eq!(
+    bytemuck::from_bytes_slice_mut::<u16>(bytes),
+    Err(PodCastError::OutputSliceWouldHaveSlop)
+  );
+  assert_eq!(
+    bytemuck::from_bytes_slice_mut::<u16>(&mut bytes[1..5]),
+    Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+  );
+
+  // ZSTs don't divide by zero
+  assert_eq!(bytemuck::len_of_as::<()>(bytes), 0);
+  assert_eq!(bytemuck::len_of_as::<[u16; 0]>(&[]), 0);
+}
+
+#[test]
+fn test_try_read_finite() {
+  use checked::{try_read_finite_f32, try_read_finite_f64, CheckedCastError};
+
+  let bytes = 1.5_f32.to_bits().to_ne_bytes();
+  assert_eq!(try_read_finite_f32(&bytes), Ok(1.5));
+  // unaligned is fine
+  let mut buf = [0_u8; 9];
+  buf[1..].copy_from_slice(&(-2.25_f64).to_bits().to_ne_bytes());
+  assert_eq!(try_read_finite_f64(&buf[1..]), Ok(-2.25));
+
+  let nan = core::f32::NAN.to_bits().to_ne_bytes();
+  assert_eq!(
+    try_read_finite_f32(&nan),
+    Err(CheckedCastError::InvalidBitPattern)
+  );
+  let inf = core::f64::INFINITY.to_bits().to_ne_bytes();
+  assert_eq!(
+    try_read_finite_f64(&inf),
+    Err(CheckedCastError::InvalidBitPattern)
+  );
+  assert_eq!(
+    try_read_finite_f32(&bytes[..3]),
+    Err(CheckedCastError::PodCastError(PodCastError::SizeMismatch))
+  );
+}
+
+#[test]
+fn test_try_from_bytes_with_layout() {
+  use core::alloc::Layout;
+
+  #[repr(C, align(4096))]
+  struct Page([u8; 4096]);
+  let mut page = Page([0; 4096]);
+  page.0[..8].copy_from_slice(&7_u64.to_ne_bytes());
+  page.0[8..16].copy_from_slice(&9_u64.to_ne_bytes());
+
+  let page_layout = Layout::from_size_align(8, 4096).unwrap();
+  assert_eq!(
+    try_from_bytes_with_layout::<u64>(&page.0[..8], page_layout),
+    Ok(&7)
+  );
+  // aligned enough for a `u64`, but not for the requested layout
+  assert_eq!(
+    try_from_bytes_with_layout::<u64>(&page.0[8..16], page_layout),
+    Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+  );
+  assert_eq!(
+    try_from_bytes_with_layout::<u64>(&page.0[8..16], Layout::new::<u64>()),
+    Ok(&9)
+  );
+  assert_eq!(
+    try_from_bytes_with_layout::<u64>(&page.0[..9], page_layout),
+    Err(PodCastError::SizeMismatch)
+  );
+
+  // layouts that don't fit `T` at all
+  assert_eq!(
+    try_from_bytes_with_layout::<u64>(&page.0[..8], Layout::new::<u32>()),
+    Err(PodCastError::SizeMismatch)
+  );
+  let under_aligned = Layout::from_size_align(8, 1).unwrap();
+  assert_eq!(
+    try_from_bytes_with_layout::<u64>(&page.0[..8], under_aligned),
+    Err(PodCastError::AlignmentMismatch)
+  );
+}
+
+#[test]
+fn test_find_pod() {
+  let magic = 0xCAFE_F00D_u32;
+  let mut haystack = [0_u8; 32];
+  haystack[13..17].copy_from_slice(&magic.to_ne_bytes());
+  assert_eq!(find_pod(&haystack, &magic), Some(13));
+
+  // the first match wins
+  haystack[22..26].copy_from_slice(&magic.to_ne_bytes());
+  assert_eq!(find_pod(&haystack, &magic), Some(13));
+
+  assert_eq!(find_pod(&haystack, &0x1234_5678_u32), None);
+  assert_eq!(find_pod(&haystack[..16], &magic), None);
+  assert_eq!(find_pod(&[], &magic), None);
+  assert_eq!(find_pod(&haystack, &()), Some(0));
+}
+
+#[test]
+fn test_starts_with_pod() {
+  let magic = 0xCAFE_F00D_u32;
+  let mut buf = [0_u8; 8];
+  buf[..4].copy_from_slice(&magic.to_ne_bytes());
+  assert!(starts_with_pod(&buf, &magic));
+  assert!(starts_with_pod(&buf[..4], &magic));
+  assert!(!starts_with_pod(&buf[..3], &magic));
+  assert!(!starts_with_pod(&buf[1..], &magic));
+  assert!(!starts_with_pod(&buf, &0xCAFE_F00C_u32));
+  assert!(starts_with_pod(&[], &()));
+}
+
+#[test]
+#[cfg(feature = "const_fn_trait_bound")]
+fn test_starts_with_pod_const() {
+  const MAGIC: u32 = u32::from_ne_bytes(*b"BMUK");
+  const GOOD: bool = starts_with_pod(b"BMUK\x01\x00", &MAGIC);
+  const SHORT: bool = starts_with_pod(b"BMU", &MAGIC);
+  const BAD: bool = starts_with_pod(b"BMUL\x01\x00", &MAGIC);
+  assert!(GOOD);
+  assert!(!SHORT);
+  assert!(!BAD);
+}
+
+#[test]
+fn test_cast_slice_u8_wide_round_trip() {
+  use core::slice::from_raw_parts;
+
+  let words: [u64; 8] = [1, 2, 3, 4, 5, 6, 7, u64::max_value()];
+  let all_bytes: &[u8] = cast_slice(&words);
+  for start in 0..8 {
+    for end in start..all_bytes.len() {
+      let bytes = &all_bytes[start..end];
+      let len = end - start;
+
+      let expected_u32 = if start % 4 != 0 {
+        Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+      } else if len % 4 != 0 {
+        Err(PodCastError::OutputSliceWouldHaveSlop)
+      } else {
+        Ok(unsafe { from_raw_parts(bytes.as_ptr() as *const u32, len / 4) })
+      };
+      assert_eq!(try_cast_slice::<u8, u32>(bytes), expected_u32);
+
+      let expected_u64 = if start % 8 != 0 {
+        Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned)
+      } else if len % 8 != 0 {
+        Err(PodCastError::OutputSliceWouldHaveSlop)
+      } else {
+        Ok(unsafe { from_raw_parts(bytes.as_ptr() as *const u64, len / 8) })
+      };
+      assert_eq!(try_cast_slice::<u8, u64>(bytes), expected_u64);
+
+      if let Ok(wide) = expected_u32 {
+        let back: &[u8] = cast_slice(wide);
+        assert_eq!(back.as_ptr(), bytes.as_ptr());
+        assert_eq!(back, bytes);
+      }
+      if let Ok(wide) = expected_u64 {
+        let back: &[u8] = cast_slice(wide);
+        assert_eq!(back.as_ptr(), bytes.as_ptr());
+        assert_eq!(back, bytes);
+      }
+    }
+  }
+}
+
+#[test]
+fn test_cast_slice_u8_wide_is_zero_copy() {
+  // Casting between `u8` and wider integers never looks at the elements, so
+  // the output is always a view of the input, however long it is.
+  let mut words = [0_u64; 4096];
+  let words_ptr = words.as_ptr() as usize;
+
+  let bytes: &[u8] = cast_slice(&words);
+  assert_eq!(bytes.as_ptr() as usize, words_ptr);
+  assert_eq!(bytes.len(), 4096 * 8);
+
+  let halves: &[u32] = cast_slice(bytes);
+  assert_eq!(halves.as_ptr() as usize, words_ptr);
+  assert_eq!(halves.len(), 4096 * 2);
+
+  let bytes: &mut [u8] = cast_slice_mut(&mut words);
+  bytes[4095 * 8..].copy_from_slice(&0x0102_0304_0506_0708_u64.to_ne_bytes());
+  let back: &[u64] = cast_slice(bytes);
+  assert_eq!(back.as_ptr() as usize, words_ptr);
+  assert_eq!(back[4095], 0x0102_0304_0506_0708);
+  assert_eq!(words[4095], 0x0102_0304_0506_0708);
+}
+
+#[test]
+fn test_checked_cast_slice_nonzero() {
+  use checked::CheckedCastError;
+  use core::num::{NonZeroI16, NonZeroU32, NonZeroU64};
+
+  let words: [u32; 4] = [7, 1, 0xFFFF_FFFF, 42];
+  let bytes: &[u8] = cast_slice(&words);
+  let ids: &[NonZeroU32] = checked::try_cast_slice_indexed(bytes).unwrap();
+  assert_eq!(ids.len(), 4);
+  assert_eq!(ids[3], NonZeroU32::new(42).unwrap());
+  let ids: &[NonZeroU32] = checked::cast_slice(bytes);
+  assert_eq!(ids[0].get(), 7);
+
+  let words: [u32; 4] = [7, 1, 0, 0];
+  let bytes: &[u8] = cast_slice(&words);
+  assert_eq!(
+    checked::try_cast_slice_indexed::<u8, NonZeroU32>(bytes),
+    Err((2, CheckedCastError::InvalidBitPattern))
+  );
+  assert_eq!(
+    checked::try_cast_slice::<u8, NonZeroU32>(bytes),
+    Err(CheckedCastError::InvalidBitPattern)
+  );
+  // the index is in the output slice, not the input
+  let words: [u32; 3] = [0x0001_0001, 0x0002_0002, 0];
+  assert_eq!(
+    checked::try_cast_slice_indexed::<u32, NonZeroI16>(&words),
+    Err((4, CheckedCastError::InvalidBitPattern))
+  );
+  assert_eq!(
+    checked::try_cast_slice_indexed::<u8, NonZeroU32>(&bytes[..3]),
+    Err((0, PodCastError::OutputSliceWouldHaveSlop.into()))
+  );
+  assert_eq!(
+    checked::try_from_bytes::<NonZeroU64>(&[0; 8]),
+    Err(CheckedCastError::InvalidBitPattern)
+  );
+}
+
+#[cfg(feature = "maybe_uninit")]
+#[test]
+fn test_read_unaligned_into_uninit() {
+  use core::mem::MaybeUninit;
+
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  #[repr(C)]
+  struct Header {
+    magic: u32,
+    len: u16,
+    flags: u16,
+  }
+  unsafe impl Zeroable for Header {}
+  unsafe impl Pod for Header {}
+
+  let header = Header { magic: 0xCAFE_F00D, len: 12, flags: 3 };
+  let mut buf = [0_u8; 9];
+  buf[1..].copy_from_slice(bytes_of(&header));
+
+  let mut slot = MaybeUninit::<Header>::uninit();
+  let read = read_unaligned_into_uninit(&buf[1..], &mut slot);
+  assert_eq!(*read, header);
+  read.flags = 4;
+  let value = unsafe { slot.assume_init() };
+  assert_eq!(value, Header { flags: 4, ..header });
+
+  let mut slot = MaybeUninit::<Header>::uninit();
+  assert_eq!(
+    try_read_unaligned_into_uninit(&buf, &mut slot),
+    Err(PodCastError::SizeMismatch)
+  );
+}
+
+#[test]
+fn test_swap_endianness_slice() {
+  let mut words: [u32; 3] = [0x0102_0304, 0x0506_0708, 0x090A_0B0C];
+  swap_endianness_slice(&mut words);
+  assert_eq!(words, [0x0403_0201, 0x0807_0605, 0x0C0B_0A09]);
+
+  // not the same as reversing all the bytes
+  let mut reversed: [u32; 3] = [0x0102_0304, 0x0506_0708, 0x090A_0B0C];
+  cast_slice_mut::<u32, u8>(&mut reversed).reverse();
+  assert_ne!(words, reversed);
+  assert_eq!(reversed, [0x0C0B_0A09, 0x0807_0605, 0x0403_0201]);
+
+  let mut bytes = [1_u8, 2, 3];
+  swap_endianness_slice(&mut bytes);
+  assert_eq!(bytes, [1, 2, 3]);
+
+  let mut floats = [1.5_f64, -2.0];
+  swap_endianness_slice(&mut floats);
+  swap_endianness_slice(&mut floats);
+  assert_eq!(floats, [1.5, -2.0]);
+}
+
+#[test]
+fn test_pod_iter() {
+  let words: [u32; 4] = [0x0102_0304, 0x0506_0708, 0x090A_0B0C, 0x0D0E_0F10];
+  let all_bytes: &[u8] = cast_slice(&words);
+  // unaligned, with 2 spare bytes on the end
+  let bytes = &all_bytes[1..15];
+
+  let iter = pod_iter::<u32>(bytes);
+  assert_eq!(iter.len(), bytes.len() / 4);
+  assert_eq!(iter.len(), len_of_as::<u32>(bytes));
+
+  let expected: Vec<u32> =
+    bytes.chunks_exact(4).map(pod_read_unaligned).collect();
+  assert_eq!(pod_iter::<u32>(bytes).collect::<Vec<_>>(), expected);
+  assert_eq!(pod_iter::<u32>(bytes).next_back(), Some(expected[2]));
+
+  let mut iter = pod_iter::<u32>(bytes);
+  iter.next();
+  assert_eq!(iter.len(), 2);
+
+  assert_eq!(pod_iter::<u32>(&bytes[..3]).len(), 0);
+  assert_eq!(pod_iter::<()>(bytes).len(), 0);
+}
+
+#[test]
+fn test_write_bytes_of() {
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  #[repr(C)]
+  struct Record {
+    id: u32,
+    kind: u16,
+    level: u8,
+    flags: u8,
+  }
+  unsafe impl Zeroable for Record {}
+  unsafe impl Pod for Record {}
+
+  let record = Record { id: 7, kind: 2, level: 9, flags: 1 };
+  let mut buf = [0xFF_u8; 12];
+  assert_eq!(write_bytes_of(&record, &mut buf), Ok(8));
+  assert_eq!(&buf[..8], bytes_of(&record));
+  assert_eq!(&buf[8..], &[0xFF; 4]);
+
+  // the tail of a buffer, for writing several values in a row
+  assert_eq!(write_bytes_of(&0x0102_u16, &mut buf[8..]), Ok(2));
+  assert_eq!(buf[8..10], 0x0102_u16.to_ne_bytes());
+
+  let mut small = [0_u8; 7];
+  assert_eq!(
+    write_bytes_of(&record, &mut small),
+    Err(PodCastError::SizeMismatch)
+  );
+  assert_eq!(small, [0; 7]);
+  assert_eq!(write_bytes_of(&(), &mut []), Ok(0));
+}
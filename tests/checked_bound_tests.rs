@@ -0,0 +1,93 @@
+#![cfg(feature = "unsound_bound_checked_impl")]
+
+use bytemuck::checked::{self, CheckedCastError};
+use core::mem::size_of;
+use core::ops::Bound;
+
+#[repr(C, align(4))]
+struct Aligned([u8; 8]);
+
+/// The (widened) tag of a real `Bound` value.
+fn tag_of(b: &Bound<u32>) -> u32 {
+  unsafe { *(b as *const Bound<u32> as *const u32) }
+}
+
+/// Bytes for a `Bound<u32>`, using the tag of `like` and putting the value
+/// after the tag.
+fn bound_bytes(like: &Bound<u32>, value: u32) -> Aligned {
+  let mut bytes = [0; 8];
+  bytes[..4].copy_from_slice(&tag_of(like).to_ne_bytes());
+  bytes[8 - size_of::<u32>()..].copy_from_slice(&value.to_ne_bytes());
+  Aligned(bytes)
+}
+
+#[test]
+fn test_checked_bound() {
+  assert_eq!(size_of::<Bound<u32>>(), 8);
+
+  let bytes = bound_bytes(&Bound::Included(0), 7);
+  assert_eq!(
+    checked::try_from_bytes::<Bound<u32>>(&bytes.0),
+    Ok(&Bound::Included(7))
+  );
+  let bytes = bound_bytes(&Bound::Excluded(0), 8);
+  assert_eq!(
+    checked::try_from_bytes::<Bound<u32>>(&bytes.0),
+    Ok(&Bound::Excluded(8))
+  );
+  let bytes = bound_bytes(&Bound::Unbounded, 9);
+  assert_eq!(
+    checked::try_from_bytes::<Bound<u32>>(&bytes.0),
+    Ok(&Bound::Unbounded)
+  );
+
+  // the value bytes line up with the value of a real `Bound`
+  let real = Bound::Excluded(0x0102_0304_u32);
+  let bytes = bound_bytes(&real, 0x0102_0304);
+  assert_eq!(checked::try_from_bytes::<Bound<u32>>(&bytes.0), Ok(&real));
+
+  // a tag that none of the variants use
+  let tags = [
+    tag_of(&Bound::Included(0)),
+    tag_of(&Bound::Excluded(0)),
+    tag_of(&Bound::Unbounded),
+  ];
+  let unused = (0..).find(|t| !tags.contains(t)).unwrap();
+  let mut bytes = bound_bytes(&Bound::Unbounded, 0);
+  bytes.0[..4].copy_from_slice(&unused.to_ne_bytes());
+  assert_eq!(
+    checked::try_from_bytes::<Bound<u32>>(&bytes.0),
+    Err(CheckedCastError::InvalidBitPattern)
+  );
+
+  // every byte of the widened tag counts
+  let mut bytes = bound_bytes(&Bound::Included(0), 7);
+  let wide = tag_of(&Bound::Included(0)) | 0x100;
+  bytes.0[..4].copy_from_slice(&wide.to_ne_bytes());
+  assert_eq!(
+    checked::try_from_bytes::<Bound<u32>>(&bytes.0),
+    Err(CheckedCastError::InvalidBitPattern)
+  );
+}
+
+#[test]
+fn test_checked_bound_by_value() {
+  // these copy the `Bits` as a value before checking it
+  let bytes = bound_bytes(&Bound::Excluded(0), 8);
+  assert_eq!(
+    checked::try_pod_read_unaligned::<Bound<u32>>(&bytes.0),
+    Ok(Bound::Excluded(8))
+  );
+  let words: [u32; 2] = [tag_of(&Bound::Included(0)), 5];
+  assert_eq!(checked::try_cast::<_, Bound<u32>>(words), Ok(Bound::Included(5)));
+  let words: [u32; 2] = [tag_of(&Bound::Included(0)) | 0x100, 5];
+  assert_eq!(
+    checked::try_cast::<_, Bound<u32>>(words),
+    Err(CheckedCastError::InvalidBitPattern)
+  );
+
+  // the tag is as wide as the value for the other types too
+  let unbounded: Bound<u16> = Bound::Unbounded;
+  let tag = unsafe { *(&unbounded as *const Bound<u16> as *const u16) };
+  assert_eq!(checked::try_cast::<_, Bound<u16>>([tag, 0]), Ok(unbounded));
+}
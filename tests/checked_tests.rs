@@ -0,0 +1,33 @@
+#![cfg(feature = "min_const_generics")]
+
+use bytemuck::{
+  checked::{self, CheckedCastError},
+  PodCastError,
+};
+
+#[test]
+fn test_try_cast_array() {
+  let bools: [bool; 4] = checked::try_cast_array([1_u8, 0, 0, 1]).unwrap();
+  assert_eq!(bools, [true, false, false, true]);
+
+  assert_eq!(
+    checked::try_cast_array::<u8, bool, 4>([1, 0, 7, 9]),
+    Err((2, CheckedCastError::InvalidBitPattern))
+  );
+
+  let chars: [char; 2] = checked::cast_array([0x61_u32, 0x62]);
+  assert_eq!(chars, ['a', 'b']);
+  assert_eq!(
+    checked::try_cast_array::<u32, char, 2>([0x61, 0xD800]),
+    Err((1, CheckedCastError::InvalidBitPattern))
+  );
+
+  assert_eq!(
+    checked::try_cast_array::<u16, bool, 2>([1, 0]),
+    Err((0, CheckedCastError::PodCastError(PodCastError::SizeMismatch)))
+  );
+  assert!(std::panic::catch_unwind(|| {
+    let _: [bool; 2] = checked::cast_array([0_u8, 3]);
+  })
+  .is_err());
+}
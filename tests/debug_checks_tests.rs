@@ -0,0 +1,23 @@
+#![cfg(feature = "debug_checks")]
+
+use bytemuck::from_bytes_unchecked;
+use std::panic;
+
+#[test]
+fn test_from_bytes_unchecked_checks() {
+  let words = [0x0102_0304_u32, 0x0506_0708];
+  let bytes: &[u8] = bytemuck::cast_slice(&words);
+  assert_eq!(unsafe { from_bytes_unchecked::<u32>(&bytes[4..]) }, &words[1]);
+
+  // misaligned
+  let result = panic::catch_unwind(|| unsafe {
+    *from_bytes_unchecked::<u32>(&bytes[1..5])
+  });
+  assert!(result.is_err());
+
+  // too short
+  let result = panic::catch_unwind(|| unsafe {
+    *from_bytes_unchecked::<u32>(&bytes[..3])
+  });
+  assert!(result.is_err());
+}
@@ -21,6 +21,7 @@ fn test_transparent_slice() {
 #[test]
 fn test_transparent_basic() {
   #[derive(Default)]
+  #[allow(dead_code)]
   struct SomeStruct(u32);
 
   #[repr(transparent)]
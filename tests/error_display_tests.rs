@@ -0,0 +1,79 @@
+//! The error messages are formatted into a fixed buffer, to show that they
+//! don't need an allocator.
+
+use bytemuck::{
+  checked::{CheckedCastError, FieldError, VerboseCheckedCastError},
+  PodCastError,
+};
+use core::fmt::Write;
+
+struct Buf {
+  bytes: [u8; 128],
+  len: usize,
+}
+
+impl Write for Buf {
+  fn write_str(&mut self, s: &str) -> core::fmt::Result {
+    let end = self.len + s.len();
+    if end > self.bytes.len() {
+      return Err(core::fmt::Error);
+    }
+    self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+    self.len = end;
+    Ok(())
+  }
+}
+
+fn display<D: core::fmt::Display>(d: D, f: impl FnOnce(&str)) {
+  let mut buf = Buf { bytes: [0; 128], len: 0 };
+  write!(buf, "{}", d).unwrap();
+  f(core::str::from_utf8(&buf.bytes[..buf.len]).unwrap())
+}
+
+#[test]
+fn test_pod_cast_error_display() {
+  display(PodCastError::TargetAlignmentGreaterAndInputNotAligned, |s| {
+    assert_eq!(s, "the input isn't aligned for the target type")
+  });
+  display(PodCastError::OutputSliceWouldHaveSlop, |s| {
+    assert_eq!(
+      s,
+      "the input isn't a whole number of elements of the target type"
+    )
+  });
+  display(PodCastError::SizeMismatch, |s| {
+    assert_eq!(s, "the input size doesn't match the size of the target type")
+  });
+  display(PodCastError::AlignmentMismatch, |s| {
+    assert_eq!(
+      s,
+      "the alignment of the input and target types must be the same"
+    )
+  });
+}
+
+#[test]
+fn test_checked_cast_error_display() {
+  display(CheckedCastError::PodCastError(PodCastError::SizeMismatch), |s| {
+    assert_eq!(s, "the input size doesn't match the size of the target type")
+  });
+  display(CheckedCastError::InvalidBitPattern, |s| {
+    assert_eq!(s, "the input is an invalid bit pattern for the target type")
+  });
+
+  let field = FieldError { field: Some("kind"), offset: 4 };
+  display(VerboseCheckedCastError::InvalidField(field), |s| {
+    assert_eq!(s, "invalid bit pattern in field `kind` (at byte offset 4)")
+  });
+  display(
+    VerboseCheckedCastError::PodCastError(
+      PodCastError::OutputSliceWouldHaveSlop,
+    ),
+    |s| {
+      assert_eq!(
+        s,
+        "the input isn't a whole number of elements of the target type"
+      )
+    },
+  );
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "min_const_generics")]
+
+use bytemuck::*;
+
+#[test]
+fn test_fixed_cast_from_bytes() {
+  let raw: [i32; 4] = [0x100, 0x180, -0x80, 0x1];
+  let bytes: &[u8] = cast_slice(&raw);
+
+  let samples: &[Fixed<8>] = cast_slice(bytes);
+  assert_eq!(samples.len(), 4);
+  assert_eq!(samples[0].to_f32(), 1.0);
+  assert_eq!(samples[1].to_f32(), 1.5);
+  assert_eq!(samples[2].to_f32(), -0.5);
+  assert_eq!(samples[3].to_f32(), 1.0 / 256.0);
+  assert_eq!(samples[3].to_bits(), 1);
+}
+
+#[test]
+fn test_fixed_conversions() {
+  let x = Fixed::<16>::from_f32(3.25);
+  assert_eq!(x.to_bits(), 3 * 65536 + 65536 / 4);
+  assert_eq!(x.to_f32(), 3.25);
+
+  // precision is 1/256, anything finer is truncated toward zero.
+  let y = Fixed::<8>::from_f32(0.7);
+  assert!((y.to_f32() - 0.7).abs() < 1.0 / 256.0);
+  assert_eq!(Fixed::<8>::from_f32(-0.001).to_bits(), 0);
+
+  assert_eq!(Fixed::<8>::from_f32(f32::MAX).to_bits(), i32::MAX);
+  assert_eq!(Fixed::<8>::zeroed(), Fixed::from_f32(0.0));
+}
+
+#[test]
+fn test_fixed_arithmetic() {
+  let a = Fixed::<16>::from_f32(1.5);
+  let b = Fixed::<16>::from_f32(0.25);
+  assert_eq!((a + b).to_f32(), 1.75);
+  assert_eq!((b - a).to_f32(), -1.25);
+
+  let mut c = a;
+  c += b;
+  c -= Fixed::from_f32(1.0);
+  assert_eq!(c.to_f32(), 0.75);
+}
+
+#[test]
+fn test_fixed_frac_bounds() {
+  // all 31 bits below the sign bit are fractional
+  let half = Fixed::<31>::from_bits(1 << 30);
+  assert_eq!(half.to_f32(), 0.5);
+  assert_eq!(Fixed::<31>::from_f32(-1.0).to_bits(), i32::min_value());
+  assert_eq!(Fixed::<31>::from_f32(0.25).to_bits(), 1 << 29);
+
+  // no fractional bits at all
+  assert_eq!(Fixed::<0>::from_f32(7.9).to_bits(), 7);
+  assert_eq!(Fixed::<0>::from_bits(-3).to_f32(), -3.0);
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "half")]
+
+use bytemuck::{cast_slice, Zeroable};
+use half::{bf16, f16};
+
+#[test]
+fn test_f16_zeroed_is_positive_zero() {
+  let z = f16::zeroed();
+  assert_eq!(z, f16::ZERO);
+  assert!(z.is_sign_positive());
+  let z = bf16::zeroed();
+  assert_eq!(z, bf16::ZERO);
+  assert!(z.is_sign_positive());
+}
+
+#[test]
+fn test_f16_cast_slice_round_trip() {
+  let halves: [f16; 4] =
+    [f16::from_f32(1.0), f16::from_f32(-2.5), f16::ZERO, f16::INFINITY];
+  let bytes: &[u8] = cast_slice(&halves);
+  assert_eq!(bytes.len(), 8);
+  assert_eq!(bytes[0..2], halves[0].to_bits().to_ne_bytes());
+  let back: &[f16] = cast_slice(bytes);
+  assert_eq!(back, &halves[..]);
+
+  let brains: [bf16; 2] = [bf16::from_f32(0.5), bf16::NEG_INFINITY];
+  let bits: &[u16] = cast_slice(&brains);
+  assert_eq!(bits, &[brains[0].to_bits(), brains[1].to_bits()][..]);
+}
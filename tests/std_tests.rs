@@ -43,3 +43,221 @@ fn test_zero_sized_box_alloc() {
   unsafe impl Zeroable for Empty {}
   let _: Box<Empty> = try_zeroed_box().unwrap();
 }
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_pod_tuple_round_trip() {
+  let tuple = (0xAA_u8, 0x11223344_u32, 0x5566_u16);
+  let mut out = vec![0xFF];
+  tuple.write_bytes(&mut out);
+
+  let mut expected = vec![0xFF, 0xAA];
+  expected.extend_from_slice(&0x11223344_u32.to_ne_bytes());
+  expected.extend_from_slice(&0x5566_u16.to_ne_bytes());
+  assert_eq!(out, expected);
+  assert_eq!(<(u8, u32, u16)>::BYTE_LEN, 7);
+
+  assert_eq!(<(u8, u32, u16)>::read_bytes(&out[1..]), Ok(tuple));
+  assert_eq!(
+    <(u8, u32, u16)>::read_bytes(&out),
+    Err(checked::CheckedCastError::PodCastError(PodCastError::SizeMismatch))
+  );
+}
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_pod_tuple_checked_fields() {
+  let mut out = Vec::new();
+  (true, 'x', 7_i8).write_bytes(&mut out);
+  assert_eq!(<(bool, char, i8)>::read_bytes(&out), Ok((true, 'x', 7)));
+
+  out[0] = 2;
+  assert_eq!(
+    <(bool, char, i8)>::read_bytes(&out),
+    Err(checked::CheckedCastError::InvalidBitPattern)
+  );
+}
+
+#[test]
+fn test_zeroed_option_nonnull() {
+  use core::ptr::NonNull;
+
+  let p: Option<NonNull<u8>> = Zeroable::zeroed();
+  assert!(p.is_none());
+  let p = <Option<NonNull<[u32; 4]>>>::zeroed();
+  assert!(p.is_none());
+}
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_zeroed_option_box() {
+  let b: Option<Box<u32>> = Zeroable::zeroed();
+  assert!(b.is_none());
+
+  #[repr(C)]
+  struct Owner {
+    len: usize,
+    data: Option<Box<[u8; 64]>>,
+  }
+  unsafe impl Zeroable for Owner {}
+  let owner = Owner::zeroed();
+  assert_eq!(owner.len, 0);
+  assert!(owner.data.is_none());
+}
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_zeroed_size_overflow() {
+  use bytemuck::allocation::{try_zeroed_slice_box, try_zeroed_vec};
+
+  // one element past what fits in `isize::MAX` bytes is an error, and doesn't
+  // try to allocate
+  let max_u64s = isize::max_value() as usize / 8;
+  assert!(try_zeroed_vec::<u64>(max_u64s + 1).is_err());
+  assert!(try_zeroed_vec::<u64>(usize::max_value()).is_err());
+  let max_blocks = isize::max_value() as usize / 4096;
+  assert!(try_zeroed_slice_box::<[u8; 4096]>(max_blocks + 1).is_err());
+  assert!(try_zeroed_slice_box::<[[u32; 64]; 64]>(usize::max_value()).is_err());
+
+  let result = std::panic::catch_unwind(|| {
+    bytemuck::allocation::zeroed_vec::<[u64; 2]>(usize::max_value() / 2)
+  });
+  let message = result.unwrap_err();
+  assert!(message.downcast_ref::<String>().unwrap().contains("isize::MAX"));
+}
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_pod_collect_to_vec_zst() {
+  let empty: Vec<()> = bytemuck::allocation::pod_collect_to_vec::<u8, ()>(&[]);
+  assert!(empty.is_empty());
+  let result = std::panic::catch_unwind(|| {
+    bytemuck::allocation::pod_collect_to_vec::<u8, ()>(&[1, 2])
+  });
+  assert!(result.is_err());
+}
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_pod_collect_chunks_to_vec() {
+  use bytemuck::allocation::pod_collect_chunks_to_vec;
+  let words: [u32; 3] = [0x0102_0304, 0x0506_0708, 0x090A_0B0C];
+  let bytes: &[u8] = bytemuck::cast_slice(&words);
+
+  // the middle u32 straddles a chunk boundary
+  let chunks = vec![bytes[..6].to_vec(), bytes[6..].to_vec()];
+  assert_eq!(pod_collect_chunks_to_vec::<u32, _>(chunks), Ok(words.to_vec()));
+
+  // a single u32 split over several chunks, with an empty one in the middle
+  let chunks: Vec<&[u8]> =
+    vec![&bytes[..1], &bytes[1..3], &[], &bytes[3..7], &bytes[7..]];
+  assert_eq!(pod_collect_chunks_to_vec::<u32, _>(chunks), Ok(words.to_vec()));
+
+  let chunks: Vec<&[u8]> = vec![&bytes[..3], &bytes[3..5]];
+  assert_eq!(
+    pod_collect_chunks_to_vec::<u32, _>(chunks),
+    Err(PodCastError::OutputSliceWouldHaveSlop)
+  );
+
+  let none: Vec<&[u8]> = Vec::new();
+  assert_eq!(pod_collect_chunks_to_vec::<u32, _>(none), Ok(Vec::new()));
+  assert_eq!(
+    pod_collect_chunks_to_vec::<(), _>(vec![&bytes[..1]]),
+    Err(PodCastError::SizeMismatch)
+  );
+}
+
+#[test]
+fn test_pod_key() {
+  use std::collections::HashMap;
+
+  // a pod type without `Eq` or `Hash`
+  #[derive(Debug, Clone, Copy)]
+  #[repr(C)]
+  struct Point {
+    x: f32,
+    y: f32,
+  }
+  unsafe impl Zeroable for Point {}
+  unsafe impl Pod for Point {}
+
+  let mut map = HashMap::new();
+  map.insert(PodKey(Point { x: 1.0, y: 2.0 }), "a");
+  map.insert(PodKey(Point { x: 3.0, y: 4.0 }), "b");
+  assert_eq!(map.get(&PodKey(Point { x: 1.0, y: 2.0 })), Some(&"a"));
+  assert_eq!(map.get(&PodKey(Point { x: 3.0, y: 4.0 })), Some(&"b"));
+  assert_eq!(map.get(&PodKey(Point { x: 2.0, y: 1.0 })), None);
+
+  // byte-equal values are the same key, even when `==` on floats disagrees
+  let nan = Point { x: core::f32::NAN, y: 0.0 };
+  map.insert(PodKey(nan), "c");
+  map.insert(PodKey(nan), "d");
+  assert_eq!(map.len(), 3);
+  assert_eq!(map[&PodKey(nan)], "d");
+  assert_ne!(
+    PodKey(Point { x: 0.0, y: 0.0 }),
+    PodKey(Point { x: -0.0, y: 0.0 })
+  );
+
+  let key = PodKey(Point { x: 5.0, y: 6.0 });
+  assert_eq!(key.y, 6.0);
+
+  // `Borrow<[u8]>` agrees with `Eq` and `Hash`, so lookups by bytes work
+  let bytes: &[u8] = core::borrow::Borrow::borrow(&key);
+  assert_eq!(bytes, bytes_of(&key.0));
+  assert_eq!(map.get(bytes_of(&Point { x: 1.0, y: 2.0 })), Some(&"a"));
+  assert_eq!(map.get(bytes_of(&nan)), Some(&"d"));
+  assert_eq!(map.get(bytes_of(&Point { x: 2.0, y: 1.0 })), None);
+  assert_eq!(map.remove(bytes_of(&Point { x: 3.0, y: 4.0 })), Some("b"));
+  assert_eq!(map.len(), 2);
+
+  fn hash_of<H: std::hash::Hash + ?Sized>(h: &H) -> u64 {
+    use std::hash::Hasher;
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    h.hash(&mut hasher);
+    hasher.finish()
+  }
+  assert_eq!(hash_of(&key), hash_of(bytes_of(&key.0)));
+}
+
+#[test]
+#[cfg(feature = "extern_crate_alloc")]
+fn test_cast_slice_cow() {
+  use bytemuck::allocation::cast_slice_cow;
+  use std::borrow::Cow;
+
+  let words: [u64; 3] = [0x0102_0304_0506_0708, 9, u64::max_value()];
+  let bytes: &[u8] = cast_slice(&words);
+
+  // aligned: borrows the same memory
+  match cast_slice_cow::<u8, u64>(bytes).unwrap() {
+    Cow::Borrowed(b) => {
+      assert_eq!(b, &words[..]);
+      assert_eq!(b.as_ptr(), words.as_ptr());
+    }
+    Cow::Owned(_) => panic!("expected a borrow"),
+  }
+
+  // misaligned: copies, with the same contents
+  let mut aligned = [0_u64; 4];
+  let buf: &mut [u8] = cast_slice_mut(&mut aligned);
+  buf[1..25].copy_from_slice(bytes);
+  match cast_slice_cow::<u8, u64>(&buf[1..25]).unwrap() {
+    Cow::Owned(v) => assert_eq!(v, words),
+    Cow::Borrowed(_) => panic!("expected a copy"),
+  }
+
+  // size problems are still errors either way
+  assert_eq!(
+    cast_slice_cow::<u8, u64>(&bytes[..20]),
+    Err(PodCastError::OutputSliceWouldHaveSlop)
+  );
+  assert_eq!(
+    cast_slice_cow::<u8, u64>(&buf[1..21]),
+    Err(PodCastError::OutputSliceWouldHaveSlop)
+  );
+  assert_eq!(
+    cast_slice_cow::<u8, ()>(&buf[1..25]),
+    Err(PodCastError::SizeMismatch)
+  );
+}
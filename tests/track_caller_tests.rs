@@ -0,0 +1,53 @@
+#![cfg(feature = "track_caller")]
+
+use std::{
+  panic,
+  sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// The line of the last panic, or 0 if it wasn't in this file.
+static LAST_PANIC_LINE: AtomicUsize = AtomicUsize::new(0);
+
+/// Runs `f`, which must panic, and gives back the line the panic says it was
+/// on.
+fn panic_line(f: impl FnOnce() + panic::UnwindSafe) -> u32 {
+  LAST_PANIC_LINE.store(0, Ordering::SeqCst);
+  assert!(panic::catch_unwind(f).is_err());
+  LAST_PANIC_LINE.load(Ordering::SeqCst) as u32
+}
+
+#[test]
+fn test_panics_report_the_call_site() {
+  panic::set_hook(Box::new(|info| {
+    let location = info.location().unwrap();
+    let line = if location.file() == file!() { location.line() } else { 0 };
+    LAST_PANIC_LINE.store(line as usize, Ordering::SeqCst);
+  }));
+
+  let line = panic_line(|| {
+    let _: u32 = bytemuck::cast(0_u8);
+  });
+  assert_eq!(line, line!() - 2);
+
+  let line = panic_line(|| {
+    let _: &u32 = bytemuck::from_bytes(&[0_u8; 3]);
+  });
+  assert_eq!(line, line!() - 2);
+
+  let line = panic_line(|| {
+    let _: &[u32] = bytemuck::cast_slice(&[0_u8; 3]);
+  });
+  assert_eq!(line, line!() - 2);
+
+  let line = panic_line(|| {
+    let _: &u16 = bytemuck::cast_ref(&0_u8);
+  });
+  assert_eq!(line, line!() - 2);
+
+  let line = panic_line(|| {
+    let _: bool = bytemuck::checked::cast(2_u8);
+  });
+  assert_eq!(line, line!() - 2);
+
+  drop(panic::take_hook());
+}
@@ -109,3 +109,99 @@ fn test_transparent_wrapper() {
     assert_eq!(&*l, &0);
   }
 }
+
+#[test]
+fn test_new_unit() {
+  use bytemuck::{cast_slice, cast_slice_mut, new_unit, TransparentWrapper};
+
+  new_unit!(
+    #[derive(Debug, PartialEq, PartialOrd)]
+    Meters,
+    f32
+  );
+  new_unit!(Seconds, f64);
+
+  assert_eq!(Meters::new(1.5).get(), 1.5);
+  assert_eq!(Seconds(2.0).get(), 2.0);
+  assert_eq!(<Meters as bytemuck::Zeroable>::zeroed(), Meters(0.0));
+
+  let mut raw = [1.0_f32, 2.0, 3.0, 4.0];
+  let lengths: &[Meters] = cast_slice(&raw);
+  assert_eq!(lengths, &[Meters(1.0), Meters(2.0), Meters(3.0), Meters(4.0)]);
+  let back: &[f32] = cast_slice(lengths);
+  assert_eq!(back, &raw[..]);
+
+  let lengths: &mut [Meters] = Meters::wrap_slice_mut(&mut raw);
+  lengths[3] = Meters::new(10.0);
+  assert_eq!(Meters::peel_slice(lengths), &[1.0, 2.0, 3.0, 10.0]);
+  let bytes: &mut [u8] = cast_slice_mut(lengths);
+  assert_eq!(bytes.len(), 16);
+  assert_eq!(raw[3], 10.0);
+}
+
+#[test]
+fn test_impl_transparent_as_ref() {
+  use bytemuck::{impl_transparent_as_ref, TransparentWrapper};
+  use core::borrow::Borrow;
+
+  #[repr(transparent)]
+  struct Counter(u32);
+  unsafe impl TransparentWrapper<u32> for Counter {}
+  impl_transparent_as_ref!(Counter, u32);
+
+  let mut c = Counter(5);
+  let inner: &u32 = c.as_ref();
+  assert_eq!(*inner, 5);
+
+  *AsMut::<u32>::as_mut(&mut c) += 1;
+  assert_eq!(c.0, 6);
+  assert_eq!(Borrow::<u32>::borrow(&c), &6);
+
+  // the conversions are the same place in memory, not a copy
+  let inner: &u32 = c.as_ref();
+  assert_eq!(inner as *const u32, &c as *const Counter as *const u32);
+}
+
+#[test]
+fn test_impl_transparent_as_ref_borrow_lookup() {
+  use bytemuck::{impl_transparent_as_ref, TransparentWrapper};
+  use std::collections::HashMap;
+
+  // `Borrow` lets a map keyed by the wrapper be searched with the inner
+  // type, which only works because the derived `Hash` and `Eq` agree with
+  // the inner type's.
+  #[derive(PartialEq, Eq, Hash)]
+  #[repr(transparent)]
+  struct UserId(u64);
+  unsafe impl TransparentWrapper<u64> for UserId {}
+  impl_transparent_as_ref!(UserId, u64);
+
+  let mut names: HashMap<UserId, &str> = HashMap::new();
+  names.insert(UserId(7), "ferris");
+  names.insert(UserId(u64::max_value()), "corro");
+
+  assert_eq!(names.get(&7_u64), Some(&"ferris"));
+  assert_eq!(names.get(&u64::max_value()), Some(&"corro"));
+  assert_eq!(names.get(&8_u64), None);
+  assert!(names.contains_key(&UserId(7)));
+  assert_eq!(names.remove(&7_u64), Some("ferris"));
+  assert_eq!(names.len(), 1);
+}
+
+pub mod documented_units {
+  #![deny(missing_docs)]
+  //! `new_unit!` passes doc comments through to the type and its field.
+
+  bytemuck::new_unit!(
+    /// A length in meters.
+    #[derive(Debug)]
+    pub Meters,
+    /// The number of meters.
+    f32
+  );
+
+  #[test]
+  fn test_new_unit_docs() {
+    assert_eq!(Meters::new(2.0).0, 2.0);
+  }
+}